@@ -35,6 +35,7 @@ fn impl_one_d_reader_macro(ast: &syn::DeriveInput) -> TokenStream {
                 image: &mut crate::BinaryBitmap<B>,
                 hints: &DecodingHintDictionary,
             ) -> Result<crate::RXingResult, Exceptions> {
+              let _time_budget = crate::decode_hints::start_time_budget(hints);
               if let Ok(res) = self._do_decode(image, hints) {
                  Ok(res)
               }else {
@@ -91,6 +91,49 @@ fn test_crop() {
     }
 }
 
+#[test]
+fn test_full_width_crop() {
+    let source = PlanarYUVLuminanceSource::new_with_all(
+        YUV.to_vec(),
+        COLS,
+        ROWS,
+        0,
+        1,
+        COLS,
+        ROWS - 2,
+        false,
+        false,
+    )
+    .unwrap();
+    let cropMatrix = source.get_matrix();
+    assert_eq!(COLS * (ROWS - 2), cropMatrix.len());
+    assert_equals(&Y, COLS, &cropMatrix, 0, COLS * (ROWS - 2));
+    for r in 0..ROWS - 2 {
+        assert_equals(&Y, (r + 1) * COLS, &source.get_row(r), 0, COLS);
+    }
+
+    let cropped = source.crop(2, 1, 3, 1).unwrap();
+    assert_eq!(vec![127, 126, 125], cropped.get_matrix());
+    assert_eq!(vec![127, 126, 125], cropped.get_row(0));
+}
+
+#[test]
+fn test_matrix_excludes_chroma() {
+    let source = PlanarYUVLuminanceSource::new_with_all(
+        YUV.to_vec(),
+        COLS,
+        ROWS,
+        0,
+        0,
+        COLS,
+        ROWS,
+        false,
+        false,
+    )
+    .unwrap();
+    assert_eq!(Y.to_vec(), source.get_matrix());
+}
+
 #[test]
 fn test_thumbnail() {
     let source = PlanarYUVLuminanceSource::new_with_all(
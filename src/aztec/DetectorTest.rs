@@ -146,7 +146,7 @@ fn test_error_in_parameter_locator(data: &str) {
                 }
                 // try {
                 if let Err(res) = detector::Detector::new(&make_larger(&copy, 3)).detect(false) {
-                    if let Exceptions::NotFoundException(_msg) = res {
+                    if let Exceptions::NotFoundException(_reason, _msg) = res {
                         // all ok
                     } else {
                         panic!("Only Exceptions::NotFoundException allowed, got {res}");
@@ -18,7 +18,7 @@ use crate::{
     common::{
         detector::WhiteRectangleDetector,
         reedsolomon::{self, ReedSolomonDecoder},
-        BitMatrix, DefaultGridSampler, GridSampler, Quadrilateral, Result,
+        BitMatrix, GridSamplerProvider, Quadrilateral, Result,
     },
     exceptions::Exceptions,
     point, Point,
@@ -456,7 +456,7 @@ impl<'a> Detector<'_> {
      * diagonal just outside the bull's eye.
      */
     fn sample_grid(&self, image: &BitMatrix, quad: Quadrilateral) -> Result<BitMatrix> {
-        let sampler = DefaultGridSampler::default();
+        let sampler = GridSamplerProvider::get_grid_sampler();
         let dimension = self.get_dimension();
 
         let low = dimension as f32 / 2.0 - self.nb_center_layers as f32;
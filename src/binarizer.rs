@@ -81,4 +81,16 @@ pub trait Binarizer {
     fn get_width(&self) -> usize;
 
     fn get_height(&self) -> usize;
+
+    /**
+     * Reports the luminance thresholds used by get_black_matrix(), for diagnostics such as
+     * visualizing the threshold surface. The map is indexed `[y][x]` and covers the whole image,
+     * each entry applying to an equally sized block of pixels.
+     *
+     * @return The threshold map, or None if this Binarizer does not expose one or no threshold
+     *         could be estimated.
+     */
+    fn black_point_map(&self) -> Option<Vec<Vec<u32>>> {
+        None
+    }
 }
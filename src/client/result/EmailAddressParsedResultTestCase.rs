@@ -22,7 +22,9 @@
 // import org.junit.Test;
 
 use crate::{
-    client::result::{ParsedClientResult, ParsedRXingResult, ParsedRXingResultType, ResultParser},
+    client::result::{
+        parse_email, ParsedClientResult, ParsedRXingResult, ParsedRXingResultType, ResultParser,
+    },
     BarcodeFormat, RXingResult,
 };
 
@@ -150,6 +152,38 @@ fn testSMTP() {
     );
 }
 
+#[test]
+fn testParseEmail() {
+    let parse = |contents: &str| {
+        parse_email(&RXingResult::new(
+            contents,
+            Vec::new(),
+            Vec::new(),
+            BarcodeFormat::QR_CODE,
+        ))
+    };
+
+    let Some(ParsedClientResult::EmailResult(email)) =
+        parse("mailto:srowen@example.org?cc=bob@example.org&subject=Hi&body=See%20you")
+    else {
+        panic!("Expected EmailResult");
+    };
+    assert_eq!(&["srowen@example.org"], email.getTos());
+    assert_eq!(&["bob@example.org"], email.getCCs());
+    assert!(email.getBCCs().is_empty());
+    assert_eq!("Hi", email.getSubject());
+    assert_eq!("See you", email.getBody());
+
+    let Some(ParsedClientResult::EmailResult(email)) = parse("srowen@example.org") else {
+        panic!("Expected EmailResult");
+    };
+    assert_eq!(&["srowen@example.org"], email.getTos());
+    assert_eq!("", email.getSubject());
+
+    assert!(parse("srowen at example.org").is_none());
+    assert!(parse("srowen@").is_none());
+}
+
 fn do_test_single(contents: &str, to: &str, subject: &str, body: &str) {
     do_test(contents, &[to], &Vec::new(), &Vec::new(), subject, body);
 }
@@ -117,7 +117,9 @@ pub fn parse(result: &RXingResult) -> Option<ParsedClientResult> {
         ))
     } else {
         // let atext_alphanumeric = Regex::new("[a-zA-Z0-9@.!#$%&'*+\\-/=?^_`{|}~]+").unwrap();
-        if !EmailDoCoMoResultParser::isBasicallyValidEmailAddress(&rawText, &ATEXT_ALPHANUMERIC) {
+        if !EmailDoCoMoResultParser::isBasicallyValidEmailAddress(&rawText, &ATEXT_ALPHANUMERIC)
+            || !hasAddressShape(&rawText)
+        {
             return None;
         }
         Some(ParsedClientResult::EmailResult(
@@ -125,3 +127,9 @@ pub fn parse(result: &RXingResult) -> Option<ParsedClientResult> {
         ))
     }
 }
+
+/// A bare address needs exactly one `@` with something on both sides of it.
+fn hasAddressShape(address: &str) -> bool {
+    matches!(address.split_once('@'), Some((local, domain))
+        if !local.is_empty() && !domain.is_empty() && !domain.contains('@'))
+}
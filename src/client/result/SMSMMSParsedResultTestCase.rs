@@ -28,7 +28,9 @@
  */
 // public final class SMSMMSParsedRXingResultTestCase extends Assert {
 use crate::{
-    client::result::{ParsedClientResult, ParsedRXingResult, ParsedRXingResultType, ResultParser},
+    client::result::{
+        parse_sms, ParsedClientResult, ParsedRXingResult, ParsedRXingResultType, ResultParser,
+    },
     BarcodeFormat, RXingResult,
 };
 
@@ -93,3 +95,36 @@ fn do_test(contents: &str, number: &str, subject: &str, body: &str, via: &str, p
 }
 
 // }
+
+#[test]
+fn test_parse_sms() {
+    let parse = |contents: &str| {
+        parse_sms(&RXingResult::new(
+            contents,
+            Vec::new(),
+            Vec::new(),
+            BarcodeFormat::QR_CODE,
+        ))
+    };
+
+    let Some(ParsedClientResult::SMSResult(sms)) =
+        parse("sms:+15551212,+15551213?subject=foo&body=bar")
+    else {
+        panic!("Expected SMSResult");
+    };
+    assert_eq!(&vec!["+15551212", "+15551213"], sms.getNumbers());
+    assert_eq!("foo", sms.getSubject());
+    assert_eq!("bar", sms.getBody());
+
+    let Some(ParsedClientResult::SMSResult(sms)) = parse("smsto:+15551212,+15551213:hi there")
+    else {
+        panic!("Expected SMSResult");
+    };
+    assert_eq!(&vec!["+15551212", "+15551213"], sms.getNumbers());
+    assert_eq!("", sms.getSubject());
+    assert_eq!("hi there", sms.getBody());
+
+    assert!(parse("sms+15551212").is_none());
+    assert!(parse("smsto:").is_none());
+    assert!(parse("tel:+15551212").is_none());
+}
@@ -50,12 +50,11 @@ pub fn parse(result: &RXingResult) -> Option<ParsedClientResult> {
         number = &number[..body_start];
     }
 
+    // several recipients may be given, separated by commas
+    let numbers: Vec<String> = number.split(',').map(|n| n.to_owned()).collect();
+    let vias = vec![String::default(); numbers.len()];
+
     Some(ParsedClientResult::SMSResult(
-        SMSParsedRXingResult::with_singles(
-            number.to_owned(),
-            String::default(),
-            String::default(),
-            body.to_owned(),
-        ),
+        SMSParsedRXingResult::with_arrays(numbers, vias, String::default(), body.to_owned()),
     ))
 }
@@ -54,6 +54,7 @@ pub use AddressBookParsedResult::*;
 pub use CalendarParsedResult::*;
 pub use CalendarParsedResult::*;
 pub use EmailAddressParsedResult::*;
+pub use EmailAddressResultParser::parse as parse_email;
 pub use ExpandedProductParsedResult::*;
 pub use ProductParsedResult::*;
 pub use SMSParsedResult::*;
@@ -63,6 +64,23 @@ pub use VINParsedResult::*;
 mod other_parsed_result;
 pub use other_parsed_result::*;
 
+/// Parses `sms:`/`mms:` URIs (`sms:number?subject=...&body=...`) as well as the
+/// `smsto:number:body` form. Both accept several comma separated recipients.
+///
+/// Returns `None` if the content is neither form or names no recipient.
+pub fn parse_sms(result: &crate::RXingResult) -> Option<ParsedClientResult> {
+    let parsed =
+        SMSMMSResultParser::parse(result).or_else(|| SMSTOMMSTOResultParser::parse(result));
+    match &parsed {
+        Some(ParsedClientResult::SMSResult(sms))
+            if sms.getNumbers().iter().any(|number| !number.is_empty()) =>
+        {
+            parsed
+        }
+        _ => None,
+    }
+}
+
 #[cfg(test)]
 mod AddressBookParsedResultTestCase;
 #[cfg(test)]
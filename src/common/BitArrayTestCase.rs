@@ -133,6 +133,64 @@ fn test_get_next_set5() {
     }
 }
 
+#[test]
+fn test_iter_set_bits() {
+    fn brute_force(array: &BitArray) -> Vec<usize> {
+        (0..array.get_size()).filter(|i| array.get(*i)).collect()
+    }
+
+    let empty = BitArray::with_size(70);
+    assert!(empty.iter_set_bits().next().is_none());
+
+    let mut full = BitArray::with_size(70);
+    full.setRange(0, 70).unwrap();
+    assert_eq!(
+        brute_force(&full),
+        full.iter_set_bits().collect::<Vec<usize>>()
+    );
+    assert_eq!(70, full.iter_set_bits().count());
+
+    let mut sparse = BitArray::with_size(100);
+    for i in [0, 31, 32, 63, 64, 99] {
+        sparse.set(i);
+    }
+    assert_eq!(
+        vec![0, 31, 32, 63, 64, 99],
+        sparse.iter_set_bits().collect::<Vec<usize>>()
+    );
+
+    let mut r = rand::thread_rng();
+    let mut random = BitArray::new();
+    for _i in 0..200 {
+        random.appendBit(r.gen());
+    }
+    assert_eq!(
+        brute_force(&random),
+        random.iter_set_bits().collect::<Vec<usize>>()
+    );
+}
+
+#[test]
+fn test_get_next_set_word_boundaries() {
+    let mut array = BitArray::with_size(96);
+    array.set(31);
+    array.set(32);
+    assert_eq!(31, array.getNextSet(31));
+    assert_eq!(32, array.getNextSet(32));
+    assert_eq!(96, array.getNextSet(33));
+
+    let mut full_word = BitArray::with_size(96);
+    full_word.setBulk(32, u32::MAX);
+    assert_eq!(32, full_word.getNextSet(0));
+    assert_eq!(32, full_word.getNextSet(31));
+    assert_eq!(63, full_word.getNextSet(63));
+    assert_eq!(96, full_word.getNextSet(64));
+    assert_eq!(0, full_word.getNextUnset(0));
+    assert_eq!(31, full_word.getNextUnset(31));
+    assert_eq!(64, full_word.getNextUnset(32));
+    assert_eq!(64, full_word.getNextUnset(63));
+}
+
 #[test]
 fn test_set_bulk() {
     let mut array = BitArray::with_size(64);
@@ -162,6 +220,326 @@ fn test_append_bit() {
     assert_eq!(array, array_2)
 }
 
+#[test]
+fn test_append_bytes() {
+    let mut r = rand::thread_rng();
+    let payload: Vec<u8> = (0..2048).map(|_| r.gen()).collect();
+
+    let mut expected = BitArray::new();
+    expected.appendBits(0b101, 3).unwrap();
+    for byte in &payload {
+        expected.appendBits(*byte as u32, 8).unwrap();
+    }
+
+    let mut array = BitArray::new();
+    array.appendBits(0b101, 3).unwrap();
+    array.append_bytes(&payload);
+
+    assert_eq!(expected.get_size(), array.get_size());
+    for i in 0..array.get_size() {
+        assert_eq!(expected.get(i), array.get(i), "{i}");
+    }
+
+    let mut empty = BitArray::new();
+    empty.append_bytes(&[]);
+    assert_eq!(0, empty.get_size());
+}
+
+#[test]
+fn test_copy_from() {
+    let mut r = rand::thread_rng();
+    let mut source = BitArray::new();
+    for _i in 0..100 {
+        source.appendBit(r.gen());
+    }
+
+    let mut expected = BitArray::with_size(100);
+    for i in 0..100 {
+        if source.get(i) {
+            expected.set(i);
+        }
+    }
+
+    let mut target = BitArray::with_size(100);
+    target.setRange(0, 100).unwrap();
+    let words = target.getBitArray().as_ptr();
+    target.copy_from(&source).unwrap();
+    assert_eq!(words, target.getBitArray().as_ptr());
+    for i in 0..100 {
+        assert_eq!(expected.get(i), target.get(i), "{i}");
+    }
+
+    assert!(BitArray::with_size(99).copy_from(&source).is_err());
+}
+
+#[test]
+fn test_from_iterator() {
+    let empty: BitArray = std::iter::empty().collect();
+    assert_eq!(0, empty.get_size());
+
+    let bits = [true, false, true, true, false, false, false, true, true];
+    let array: BitArray = bits.into_iter().collect();
+    assert_eq!(bits.len(), array.get_size());
+    for (i, bit) in bits.iter().enumerate() {
+        assert_eq!(*bit, array.get(i), "{i}");
+    }
+    assert_eq!(" X.XX...X X", array.to_string());
+}
+
+#[test]
+fn test_to_bytes() {
+    let mut array = BitArray::new();
+    array.append_bytes(&[0xA5, 0x0F, 0xF0]);
+    array.appendBits(0b101, 3).unwrap();
+
+    let mut bytes = vec![0u8; array.getSizeInBytes()];
+    array.toBytes(0, &mut bytes, 0, 4).unwrap();
+    assert_eq!(vec![0xA5, 0x0F, 0xF0, 0b1010_0000], bytes);
+
+    let mut bytes = vec![0u8; 2];
+    array.toBytes(8, &mut bytes, 0, 2).unwrap();
+    assert_eq!(vec![0x0F, 0xF0], bytes);
+
+    // trusting getSizeInBytes with a non zero offset runs off the end
+    let mut bytes = vec![0u8; array.getSizeInBytes()];
+    assert!(array.toBytes(8, &mut bytes, 0, 4).is_err());
+    // destination too small
+    assert!(array.toBytes(0, &mut bytes, 1, 4).is_err());
+    // sizes that overflow while being checked
+    assert!(array.toBytes(0, &mut bytes, 0, usize::MAX / 4).is_err());
+    assert!(array.toBytes(usize::MAX, &mut bytes, 0, 1).is_err());
+    assert!(array.toBytes(0, &mut bytes, usize::MAX, 1).is_err());
+}
+
+#[test]
+fn test_extend() {
+    let bits = vec![
+        false, true, true, false, true, false, false, true, false, true,
+    ];
+
+    let mut expected = BitArray::new();
+    expected.appendBits(0b11, 2).unwrap();
+    for bit in &bits {
+        expected.appendBit(*bit);
+    }
+
+    let mut array = BitArray::new();
+    array.appendBits(0b11, 2).unwrap();
+    array.extend(bits.iter().copied());
+    assert_eq!(expected, array);
+
+    let collected: BitArray = bits.clone().into_iter().collect();
+    let mut manual = BitArray::new();
+    for bit in bits {
+        manual.appendBit(bit);
+    }
+    assert_eq!(manual, collected);
+}
+
+#[test]
+fn test_shift() {
+    fn naive_shift(array: &BitArray, n: isize) -> BitArray {
+        let mut shifted = BitArray::with_size(array.get_size());
+        for i in 0..array.get_size() {
+            let to = i as isize + n;
+            if array.get(i) && to >= 0 && (to as usize) < array.get_size() {
+                shifted.set(to as usize);
+            }
+        }
+        shifted
+    }
+
+    let mut r = rand::thread_rng();
+    for size in [1, 31, 32, 33, 64, 95, 100] {
+        let mut array = BitArray::with_size(size);
+        for i in 0..size {
+            if r.gen() {
+                array.set(i);
+            }
+        }
+        for n in [0, 1, 5, 31, 32, 33, 63, 64, 65, 99, 100, 150] {
+            let mut left = array.clone();
+            left.shift_left(n);
+            assert_eq!(naive_shift(&array, -(n as isize)), left, "{size} << {n}");
+
+            let mut right = array.clone();
+            right.shift_right(n);
+            assert_eq!(naive_shift(&array, n as isize), right, "{size} >> {n}");
+        }
+    }
+}
+
+#[test]
+fn test_shift_across_word_boundary() {
+    let mut array = BitArray::with_size(70);
+    array.set(30);
+    array.set(31);
+    array.set(40);
+
+    let mut left = array.clone();
+    left.shift_left(10);
+    assert_eq!(vec![20, 21, 30], left.iter_set_bits().collect::<Vec<_>>());
+
+    let mut right = array.clone();
+    right.shift_right(3);
+    assert_eq!(vec![33, 34, 43], right.iter_set_bits().collect::<Vec<_>>());
+    assert_eq!(70, right.get_size());
+}
+
+#[test]
+fn test_shift_by_size_clears() {
+    let mut array = BitArray::with_size(40);
+    array.setRange(0, 40).unwrap();
+
+    let mut left = array.clone();
+    left.shift_left(40);
+    assert_eq!(BitArray::with_size(40), left);
+
+    let mut right = array;
+    right.shift_right(40);
+    assert_eq!(BitArray::with_size(40), right);
+}
+
+#[test]
+fn test_parity() {
+    assert!(!BitArray::with_size(40).parity());
+
+    let mut array = BitArray::with_size(40);
+    array.set(0);
+    assert!(array.parity());
+    array.set(33);
+    assert!(!array.parity());
+    array.setRange(5, 12).unwrap();
+    assert!(array.parity());
+}
+
+#[test]
+fn test_crc16_ccitt() {
+    let mut array = BitArray::new();
+    array.append_bytes(b"123456789");
+    assert_eq!(
+        vec![b'1', b'2', b'3', b'4', b'5', b'6', b'7', b'8', b'9'],
+        array.to_bytes_vec()
+    );
+    assert_eq!(0x29B1, array.crc16_ccitt());
+
+    assert_eq!(0xFFFF, BitArray::new().crc16_ccitt());
+}
+
+#[test]
+fn test_rotate_left() {
+    let mut array = BitArray::with_size(40);
+    array.set(0);
+    array.set(3);
+    array.set(35);
+    array.rotate_left(4);
+    assert_eq!(vec![31, 36, 39], array.iter_set_bits().collect::<Vec<_>>());
+    assert_eq!(40, array.get_size());
+}
+
+#[test]
+fn test_rotate_left_round_trip() {
+    let mut r = rand::thread_rng();
+    for size in [1, 31, 32, 33, 64, 100] {
+        let mut array = BitArray::with_size(size);
+        for i in 0..size {
+            if r.gen() {
+                array.set(i);
+            }
+        }
+        for n in [0, size, 2 * size] {
+            let mut rotated = array.clone();
+            rotated.rotate_left(n);
+            assert_eq!(array, rotated, "{size} rotated by {n}");
+        }
+        for n in [1, 5, 31, 32, 33, 63] {
+            let n = n % size;
+            let mut rotated = array.clone();
+            rotated.rotate_left(n);
+            rotated.rotate_left(size - n);
+            assert_eq!(array, rotated, "{size} rotated by {n}");
+        }
+    }
+}
+
+#[test]
+fn test_to_bit_vec() {
+    assert!(BitArray::new().to_bit_vec().is_empty());
+
+    let mut r = rand::thread_rng();
+    for size in [1, 31, 32, 33, 70] {
+        let bits: Vec<bool> = (0..size).map(|_| r.gen()).collect();
+        let array: BitArray = bits.iter().copied().collect();
+        let round_trip = array.to_bit_vec();
+        assert_eq!(array.get_size(), round_trip.len());
+        assert_eq!(bits, round_trip);
+    }
+}
+
+#[test]
+fn test_append_bit_array() {
+    let mut r = rand::thread_rng();
+    for start_size in [0, 1, 5, 31, 32, 33, 64, 77] {
+        for other_size in [0, 1, 31, 32, 33, 1000, 1021] {
+            let start: Vec<bool> = (0..start_size).map(|_| r.gen()).collect();
+            let other: Vec<bool> = (0..other_size).map(|_| r.gen()).collect();
+
+            let mut array: BitArray = start.iter().copied().collect();
+            array.appendBitArray(other.iter().copied().collect());
+
+            let mut expected = BitArray::new();
+            for bit in start.iter().chain(other.iter()) {
+                expected.appendBit(*bit);
+            }
+
+            assert_eq!(expected, array, "{start_size} + {other_size}");
+        }
+    }
+}
+
+#[test]
+fn test_append_bit_array_by_reference() {
+    let source: BitArray = [true, false, true].into_iter().collect();
+    let mut array = BitArray::new();
+    array.append_bit_array(&source);
+    array.append_bit_array(&source);
+    assert_eq!(
+        vec![true, false, true, true, false, true],
+        array.to_bit_vec()
+    );
+    assert_eq!(3, source.get_size());
+}
+
+#[test]
+fn test_count_set_in_range() {
+    let mut r = rand::thread_rng();
+    let mut array = BitArray::with_size(100);
+    for i in 0..100 {
+        if r.gen() {
+            array.set(i);
+        }
+    }
+    for (start, end) in [
+        (0, 0),
+        (0, 100),
+        (3, 29),
+        (31, 33),
+        (30, 64),
+        (32, 64),
+        (5, 99),
+        (64, 100),
+    ] {
+        let expected = (start..end).filter(|i| array.get(*i)).count();
+        assert_eq!(
+            expected,
+            array.count_set_in_range(start, end).unwrap(),
+            "[{start}, {end})"
+        );
+    }
+    assert!(array.count_set_in_range(10, 5).is_err());
+    assert!(array.count_set_in_range(0, 101).is_err());
+}
+
 #[test]
 fn test_set_range() {
     let mut array = BitArray::with_size(64);
@@ -271,6 +649,48 @@ fn test_equals() {
     // assert_eq!(a.hash(), b.hash());
 }
 
+#[test]
+fn test_equals_ignores_capacity() {
+    use std::collections::hash_map::DefaultHasher;
+    use std::hash::{Hash, Hasher};
+
+    fn hash(array: &BitArray) -> u64 {
+        let mut hasher = DefaultHasher::new();
+        array.hash(&mut hasher);
+        hasher.finish()
+    }
+
+    let mut grown = BitArray::new();
+    for _i in 0..100 {
+        grown.appendBit(true);
+    }
+    grown.clear();
+    let fresh = BitArray::with_size(100);
+    assert_ne!(fresh.getBitArray().len(), grown.getBitArray().len());
+    assert_eq!(fresh, grown);
+    assert_eq!(hash(&fresh), hash(&grown));
+
+    grown.set(99);
+    assert_ne!(fresh, grown);
+
+    // bits past the size are not part of the array
+    let mut padded = BitArray::with_size(40);
+    padded.setBulk(32, 0xFFFF_FF00);
+    assert_eq!(BitArray::with_size(40), padded);
+}
+
+#[test]
+fn test_heap_size_bytes() {
+    let mut bits = Vec::with_capacity(10);
+    bits.extend_from_slice(&[0xFF, 0]);
+    let array = BitArray::with_initial_values(bits, 40);
+    assert_eq!(10 * std::mem::size_of::<u32>(), array.heap_size_bytes());
+
+    // 100 bits need 4 words
+    assert_eq!(16, BitArray::with_size(100).heap_size_bytes());
+    assert_eq!(0, BitArray::new().heap_size_bytes());
+}
+
 fn reverse_original(oldBits: &[u32], size: usize) -> Vec<u32> {
     let mut newBits = vec![0; oldBits.len()];
     for i in 0..size {
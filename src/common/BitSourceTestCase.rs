@@ -47,4 +47,126 @@ fn test_source() {
     assert_eq!(0, source.available());
 }
 
+#[test]
+fn test_consumed_checksum() {
+    let bytes: Vec<u8> = vec![0x01, 0xFF, 0x80, 0x7F];
+    let mut source = BitSource::with_checksum(bytes);
+    assert_eq!(0, source.consumed_checksum());
+    source.readBits(4).unwrap();
+    // first byte is only partially read
+    assert_eq!(0, source.consumed_checksum());
+    source.readBits(4).unwrap();
+    assert_eq!(0x01, source.consumed_checksum());
+    source.readBits(12).unwrap();
+    assert_eq!(0x01 + 0xFF, source.consumed_checksum());
+    source.readBits(12).unwrap();
+    assert_eq!(0x01 + 0xFF + 0x80 + 0x7F, source.consumed_checksum());
+    assert_eq!(0, source.available());
+
+    let mut plain = BitSource::new(vec![0xFF, 0xFF]);
+    plain.readBits(16).unwrap();
+    assert_eq!(0, plain.consumed_checksum());
+}
+
+#[test]
+fn test_bits() {
+    let bytes: Vec<u8> = vec![0xA5, 0x3C];
+    let mut expected = BitSource::new(bytes.clone());
+    let mut source = BitSource::new(bytes);
+    source.readBits(3).unwrap();
+    expected.readBits(3).unwrap();
+
+    let bits: Vec<bool> = source.bits().collect();
+    assert_eq!(13, bits.len());
+    for bit in bits {
+        assert_eq!(expected.readBits(1).unwrap() == 1, bit);
+    }
+    assert_eq!(0, source.available());
+    assert_eq!(2, source.getByteOffset());
+    assert_eq!(0, source.getBitOffset());
+    assert_eq!(None, source.bits().next());
+}
+
+#[test]
+fn test_read_bytes() {
+    let mut source = BitSource::with_checksum(vec![0x12, 0x34, 0x56, 0x78, 0x9A, 0xBC]);
+    source.readBits(8).unwrap();
+    assert_eq!(vec![0x34, 0x56, 0x78, 0x9A], source.read_bytes(4).unwrap());
+    assert_eq!(5, source.getByteOffset());
+    assert_eq!(0, source.getBitOffset());
+    assert_eq!(0x12 + 0x34 + 0x56 + 0x78 + 0x9A, source.consumed_checksum());
+    assert!(source.read_bytes(2).is_err());
+    assert_eq!(5, source.getByteOffset());
+    assert_eq!(vec![0xBC], source.read_bytes(1).unwrap());
+    assert!(source.read_bytes(0).unwrap().is_empty());
+}
+
+#[test]
+fn test_read_bytes_mid_byte() {
+    let mut source = BitSource::new(vec![0x12, 0x34, 0x56]);
+    source.readBits(3).unwrap();
+    assert!(matches!(
+        source.read_bytes(1),
+        Err(crate::Exceptions::IllegalStateException(_))
+    ));
+    assert_eq!(0, source.getByteOffset());
+    assert_eq!(3, source.getBitOffset());
+}
+
+#[test]
+fn test_peek_bits() {
+    let mut source = BitSource::with_checksum(vec![0x01, 0x02, 0x03, 0x04, 0x05]);
+    assert_eq!(40, source.available());
+    for numBits in [1, 3, 12, 17, 2, 5] {
+        let available = source.available();
+        let peeked = source.peek_bits(numBits).unwrap();
+        assert_eq!(available, source.available());
+        assert_eq!(peeked, source.peek_bits(numBits).unwrap());
+        assert_eq!(peeked, source.readBits(numBits).unwrap());
+        assert_eq!(available - numBits, source.available());
+    }
+    assert_eq!(0, source.available());
+    assert_eq!(0x01 + 0x02 + 0x03 + 0x04 + 0x05, source.consumed_checksum());
+
+    let source = BitSource::new(vec![0xA5]);
+    assert!(source.peek_bits(0).is_err());
+    assert!(source.peek_bits(9).is_err());
+    assert_eq!(0xA5, source.peek_bits(8).unwrap());
+    assert_eq!(0, source.getByteOffset());
+}
+
+#[test]
+fn test_read_bit() {
+    let mut source = BitSource::new(vec![0b0000_0101, 0b1000_0000]);
+    let bits: Vec<bool> = (0..10).map(|_| source.read_bit().unwrap()).collect();
+    assert_eq!(
+        vec![false, false, false, false, false, true, false, true, true, false],
+        bits
+    );
+    assert_eq!(6, source.available());
+    assert_eq!(1, source.getByteOffset());
+    assert_eq!(2, source.getBitOffset());
+}
+
+#[test]
+fn test_align_to_byte() {
+    let mut source = BitSource::with_checksum(vec![0xFF, 0x12, 0x34]);
+    // already aligned: nothing is skipped
+    source.align_to_byte();
+    assert_eq!(24, source.available());
+
+    assert!(source.read_bit().unwrap());
+    source.align_to_byte();
+    assert_eq!(16, source.available());
+    assert_eq!(0xFF, source.consumed_checksum());
+    assert_eq!(0x12, source.readBits(8).unwrap());
+
+    source.readBits(7).unwrap();
+    source.align_to_byte();
+    assert_eq!(0, source.available());
+    assert!(source.read_bit().is_err());
+    source.align_to_byte();
+    assert_eq!(0, source.available());
+}
+
 // }
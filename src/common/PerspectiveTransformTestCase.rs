@@ -19,7 +19,7 @@
 // import org.junit.Assert;
 // import org.junit.Test;
 
-use crate::point;
+use crate::{point, Exceptions};
 
 /**
  * @author Sean Owen
@@ -70,6 +70,69 @@ fn test_quadrilateral_to_quadrilateral() {
     assert_point_equals(328.09116, 334.16385, 50.0, 50.0, &pt);
 }
 
+#[test]
+fn test_transform_point() {
+    let quad1 = Quadrilateral::new(
+        point(2.0, 3.0),
+        point(10.0, 4.0),
+        point(16.0, 15.0),
+        point(4.0, 9.0),
+    );
+    let quad2 = Quadrilateral::new(
+        point(103.0, 110.0),
+        point(300.0, 120.0),
+        point(290.0, 270.0),
+        point(150.0, 280.0),
+    );
+    let pt = PerspectiveTransform::quadrilateralToQuadrilateral(quad1, quad2).expect("transform");
+
+    let mut points = [point(2.0, 3.0), point(0.5, 0.5), point(50.0, 50.0)];
+    let sources = points;
+    pt.transform_points_single(&mut points);
+    let mut x_values = sources.map(|p| p.x);
+    let mut y_values = sources.map(|p| p.y);
+    pt.transform_points_double(&mut x_values, &mut y_values);
+    for (i, (source, transformed)) in sources.iter().zip(points.iter()).enumerate() {
+        let (x, y) = pt.transform_point(source.x, source.y);
+        assert_eq!((transformed.x, transformed.y), (x, y));
+        assert_eq!((x_values[i], y_values[i]), (x, y));
+    }
+}
+
+#[test]
+fn test_quadrilateral_to_quadrilateral_checked() {
+    let quad = Quadrilateral::new(
+        point(103.0, 110.0),
+        point(300.0, 120.0),
+        point(290.0, 270.0),
+        point(150.0, 280.0),
+    );
+    let collinear = Quadrilateral::new(
+        point(0.0, 0.0),
+        point(10.0, 10.0),
+        point(20.0, 20.0),
+        point(30.0, 30.0),
+    );
+    let collapsed = Quadrilateral::new(
+        point(5.0, 5.0),
+        point(5.0, 5.0),
+        point(5.0, 5.0),
+        point(5.0, 5.0),
+    );
+
+    assert!(PerspectiveTransform::quadrilateral_to_quadrilateral_checked(quad, quad).is_ok());
+    for degenerate in [collinear, collapsed] {
+        assert_eq!(
+            Some(Exceptions::not_found_with("degenerate quadrilateral")),
+            PerspectiveTransform::quadrilateral_to_quadrilateral_checked(degenerate, quad).err()
+        );
+        assert_eq!(
+            Some(Exceptions::not_found_with("degenerate quadrilateral")),
+            PerspectiveTransform::quadrilateral_to_quadrilateral_checked(quad, degenerate).err()
+        );
+    }
+}
+
 fn assert_point_equals(
     expected_x: f32,
     expected_y: f32,
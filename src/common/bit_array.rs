@@ -18,11 +18,17 @@
 
 // import java.util.Arrays;
 
-use std::{cmp, fmt};
+use std::{
+    cmp, fmt,
+    hash::{Hash, Hasher},
+};
 
 use crate::common::Result;
 use crate::Exceptions;
 
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
 static LOAD_FACTOR: f32 = 0.75;
 
 /**
@@ -30,12 +36,45 @@ static LOAD_FACTOR: f32 = 0.75;
  *
  * @author Sean Owen
  */
-#[derive(Debug, PartialEq, Eq, Clone, Hash)]
+#[cfg_attr(
+    feature = "serde",
+    derive(Serialize, Deserialize),
+    serde(try_from = "RawBitArray")
+)]
+#[derive(Debug, Clone)]
 pub struct BitArray {
     bits: Vec<u32>,
     size: usize,
 }
 
+/// The serialized fields of a [`BitArray`], checked for consistency before use.
+#[cfg(feature = "serde")]
+#[derive(Deserialize)]
+struct RawBitArray {
+    bits: Vec<u32>,
+    size: usize,
+}
+
+#[cfg(feature = "serde")]
+impl TryFrom<RawBitArray> for BitArray {
+    type Error = Exceptions;
+
+    fn try_from(value: RawBitArray) -> Result<Self, Self::Error> {
+        // the backing array may have grown beyond what size needs
+        if value.bits.len() < value.size.div_ceil(32) {
+            return Err(Exceptions::illegal_argument_with(format!(
+                "{} words cannot hold {} bits",
+                value.bits.len(),
+                value.size
+            )));
+        }
+        Ok(Self {
+            bits: value.bits,
+            size: value.size,
+        })
+    }
+}
+
 impl BitArray {
     pub fn new() -> Self {
         Self {
@@ -65,6 +104,12 @@ impl BitArray {
         (self.size + 7) / 8
     }
 
+    /// Approximately how many bytes of heap this array holds: the allocated capacity of its
+    /// backing words, which may exceed [`BitArray::getSizeInBytes`] after appending.
+    pub fn heap_size_bytes(&self) -> usize {
+        self.bits.capacity() * std::mem::size_of::<u32>()
+    }
+
     fn ensure_capacity(&mut self, newSize: usize) {
         if newSize > self.bits.len() * 32 {
             let mut newBits = BitArray::makeArray((newSize as f32 / LOAD_FACTOR).ceil() as usize);
@@ -111,15 +156,14 @@ impl BitArray {
             return self.size;
         }
         let mut bitsOffset = from / 32;
-        let mut currentBits = self.bits[bitsOffset] as i64;
         // mask off lesser bits first
-        currentBits &= -(1 << (from & 0x1F));
+        let mut currentBits = self.bits[bitsOffset] & (u32::MAX << (from & 0x1F));
         while currentBits == 0 {
             bitsOffset += 1;
             if bitsOffset == self.bits.len() {
                 return self.size;
             }
-            currentBits = self.bits[bitsOffset] as i64;
+            currentBits = self.bits[bitsOffset];
         }
         let result = (bitsOffset * 32) + currentBits.trailing_zeros() as usize;
         cmp::min(result, self.size)
@@ -135,20 +179,34 @@ impl BitArray {
             return self.size;
         }
         let mut bitsOffset = from / 32;
-        let mut currentBits = !self.bits[bitsOffset] as i64;
         // mask off lesser bits first
-        currentBits &= -(1 << (from & 0x1F));
+        let mut currentBits = !self.bits[bitsOffset] & (u32::MAX << (from & 0x1F));
         while currentBits == 0 {
             bitsOffset += 1;
             if bitsOffset == self.bits.len() {
                 return self.size;
             }
-            currentBits = !self.bits[bitsOffset] as i64;
+            currentBits = !self.bits[bitsOffset];
         }
         let result = (bitsOffset * 32) + currentBits.trailing_zeros() as usize;
         cmp::min(result, self.size)
     }
 
+    /// Returns an iterator over the indices of all set bits, in ascending order.
+    ///
+    /// Uses [`BitArray::getNextSet`] so that runs of unset words are skipped quickly.
+    pub fn iter_set_bits(&self) -> impl Iterator<Item = usize> + '_ {
+        let mut next = self.getNextSet(0);
+        std::iter::from_fn(move || {
+            if next >= self.size {
+                return None;
+            }
+            let current = next;
+            next = self.getNextSet(current + 1);
+            Some(current)
+        })
+    }
+
     /**
      * Sets a block of 32 bits, starting at bit i.
      *
@@ -236,6 +294,30 @@ impl BitArray {
         Ok(true)
     }
 
+    /// Counts the set bits in `[start, end)`, a word at a time.
+    ///
+    /// Fails like [`BitArray::isRange`] if `end` is less than `start` or past the end of the array.
+    pub fn count_set_in_range(&self, start: usize, end: usize) -> Result<usize> {
+        if end < start || end > self.size {
+            return Err(Exceptions::ILLEGAL_ARGUMENT);
+        }
+        if end == start {
+            return Ok(0);
+        }
+        let last = end - 1;
+        let first_int = start / 32;
+        let last_int = last / 32;
+        let mut count = 0;
+        for i in first_int..=last_int {
+            let first_bit = if i > first_int { 0 } else { start & 0x1F };
+            let last_bit = if i < last_int { 31 } else { last & 0x1F };
+            // Ones from first_bit to last_bit, inclusive
+            let mask: u64 = (2 << last_bit) - (1 << first_bit);
+            count += (self.bits[i] & mask as u32).count_ones() as usize;
+        }
+        Ok(count)
+    }
+
     pub fn appendBit(&mut self, bit: bool) {
         self.ensure_capacity(self.size + 1);
         if bit {
@@ -276,15 +358,67 @@ impl BitArray {
         Ok(())
     }
 
+    /// Appends every byte in `bytes`, each one most-significant bit first.
+    ///
+    /// This is equivalent to calling `appendBits(byte as u32, 8)` for each byte, but only checks
+    /// capacity once for the whole slice.
+    pub fn append_bytes(&mut self, bytes: &[u8]) {
+        let mut next_size = self.size;
+        self.ensure_capacity(next_size + bytes.len() * 8);
+        for byte in bytes {
+            for bit in (0..8).rev() {
+                if (byte & (1 << bit)) != 0 {
+                    self.bits[next_size / 32] |= 1 << (next_size & 0x1F);
+                }
+                next_size += 1;
+            }
+        }
+        self.size = next_size;
+    }
+
     pub fn appendBitArray(&mut self, other: BitArray) {
+        self.append_bit_array(&other)
+    }
+
+    /// Appends all bits of `other` to the end of this array, leaving `other` untouched.
+    pub fn append_bit_array(&mut self, other: &BitArray) {
         let otherSize = other.size;
         self.ensure_capacity(self.size + otherSize);
-        for i in 0..otherSize {
+        self.clear_padding();
+
+        // Copy whole words at once, shifting them into place if this array isn't word aligned
+        let start = self.size / 32;
+        let shift = self.size & 0x1F;
+        let fullWords = otherSize / 32;
+        for (i, word) in other.bits.iter().take(fullWords).enumerate() {
+            if shift == 0 {
+                self.bits[start + i] = *word;
+            } else {
+                self.bits[start + i] |= word << shift;
+                self.bits[start + i + 1] = word >> (32 - shift);
+            }
+        }
+        self.size += fullWords * 32;
+
+        // Then the remaining bits one by one
+        for i in fullWords * 32..otherSize {
             //for (int i = 0; i < otherSize; i++) {
             self.appendBit(other.get(i));
         }
     }
 
+    /// Copies the contents of `other` into this array without reallocating.
+    ///
+    /// Both arrays must have the same size.
+    pub fn copy_from(&mut self, other: &BitArray) -> Result<()> {
+        if self.size != other.size {
+            return Err(Exceptions::illegal_argument_with("Sizes don't match"));
+        }
+        let words = BitArray::makeArray(self.size).len();
+        self.bits[0..words].clone_from_slice(&other.bits[0..words]);
+        Ok(())
+    }
+
     pub fn xor(&mut self, other: &BitArray) -> Result<()> {
         if self.size != other.size {
             return Err(Exceptions::illegal_argument_with("Sizes don't match"));
@@ -298,6 +432,80 @@ impl BitArray {
         Ok(())
     }
 
+    /// Shifts every bit `n` positions towards index 0, keeping the size of the array.
+    ///
+    /// Bit `i` moves to `i - n`, the first `n` bits are dropped and the last `n` positions
+    /// become unset. Shifting by `size` or more clears the array.
+    pub fn shift_left(&mut self, n: usize) {
+        let words = self.clear_padding();
+        let word_shift = n / 32;
+        let bit_shift = n & 0x1F;
+        for i in 0..words {
+            let low = self.bits.get(i + word_shift).copied().unwrap_or(0);
+            let high = if bit_shift == 0 || i + word_shift + 1 >= words {
+                0
+            } else {
+                self.bits[i + word_shift + 1] << (32 - bit_shift)
+            };
+            self.bits[i] = if i + word_shift < words {
+                (low >> bit_shift) | high
+            } else {
+                0
+            };
+        }
+    }
+
+    /// Shifts every bit `n` positions away from index 0, keeping the size of the array.
+    ///
+    /// Bit `i` moves to `i + n`, bits pushed past the end are dropped and the first `n`
+    /// positions become unset. Shifting by `size` or more clears the array.
+    pub fn shift_right(&mut self, n: usize) {
+        let words = self.clear_padding();
+        let word_shift = n / 32;
+        let bit_shift = n & 0x1F;
+        for i in (0..words).rev() {
+            self.bits[i] = if i < word_shift {
+                0
+            } else {
+                let high = self.bits[i - word_shift] << bit_shift;
+                let low = if bit_shift == 0 || i < word_shift + 1 {
+                    0
+                } else {
+                    self.bits[i - word_shift - 1] >> (32 - bit_shift)
+                };
+                high | low
+            };
+        }
+        self.clear_padding();
+    }
+
+    /// Rotates every bit `n` positions towards index 0 within `[0, size)`.
+    ///
+    /// Bit `i` moves to `(i - n) mod size`, so the bits that [`BitArray::shift_left`] would
+    /// drop wrap around to the end. `n` is reduced modulo the size of the array.
+    pub fn rotate_left(&mut self, n: usize) {
+        let n = n.checked_rem(self.size).unwrap_or(0);
+        if n == 0 {
+            return;
+        }
+        let mut wrapped = self.clone();
+        wrapped.shift_right(self.size - n);
+        self.shift_left(n);
+        for (word, wrapped_word) in self.bits.iter_mut().zip(wrapped.bits) {
+            *word |= wrapped_word;
+        }
+    }
+
+    /// Unsets any bits in the last used word which lie beyond `size`, returning the number of
+    /// words in use.
+    fn clear_padding(&mut self) -> usize {
+        let words = self.size.div_ceil(32);
+        if self.size & 0x1F != 0 {
+            self.bits[words - 1] &= (1 << (self.size & 0x1F)) - 1;
+        }
+        words
+    }
+
     /**
      *
      * @param bitOffset first bit to start writing
@@ -305,8 +513,33 @@ impl BitArray {
      *  of the internal representation, which is exposed by {@link #getBitArray()}
      * @param offset position in array to start writing
      * @param numBytes how many bytes to write
+     * @throws IllegalArgumentException if the bits to read run past the last byte of this array,
+     *  or the bytes to write do not fit in array
      */
-    pub fn toBytes(&self, bitOffset: usize, array: &mut [u8], offset: usize, numBytes: usize) {
+    pub fn toBytes(
+        &self,
+        bitOffset: usize,
+        array: &mut [u8],
+        offset: usize,
+        numBytes: usize,
+    ) -> Result<()> {
+        // the final, partially filled, byte may be read in full
+        let end_bit = numBytes
+            .checked_mul(8)
+            .and_then(|bits| bits.checked_add(bitOffset));
+        if end_bit.is_none_or(|end_bit| end_bit > self.getSizeInBytes() * 8) {
+            return Err(Exceptions::illegal_argument_with(
+                "requested bytes run past the end of the array",
+            ));
+        }
+        if offset
+            .checked_add(numBytes)
+            .is_none_or(|end| end > array.len())
+        {
+            return Err(Exceptions::illegal_argument_with(
+                "destination array is too small",
+            ));
+        }
         let mut bitOffset = bitOffset;
         for i in 0..numBytes {
             //for (int i = 0; i < numBytes; i++) {
@@ -320,6 +553,50 @@ impl BitArray {
             }
             array[offset + i] = the_byte;
         }
+        Ok(())
+    }
+
+    /// Packs the bits into `getSizeInBytes()` bytes, most-significant bit first, as
+    /// [`BitArray::toBytes`] does. Any unused bits of the final byte are zero.
+    pub fn to_bytes_vec(&self) -> Vec<u8> {
+        let mut bytes = vec![0; self.getSizeInBytes()];
+        for i in self.iter_set_bits() {
+            bytes[i / 8] |= 0x80 >> (i & 0x07);
+        }
+        bytes
+    }
+
+    /// Returns `true` if an odd number of bits are set.
+    pub fn parity(&self) -> bool {
+        self.used_words()
+            .fold(0, |acc, word| acc ^ word)
+            .count_ones()
+            & 1
+            == 1
+    }
+
+    /// Computes CRC-16/CCITT-FALSE over [`BitArray::to_bytes_vec`]: polynomial `0x1021`,
+    /// initial value `0xFFFF`, no input or output reflection and no final XOR.
+    pub fn crc16_ccitt(&self) -> u16 {
+        let mut crc: u16 = 0xFFFF;
+        for byte in self.to_bytes_vec() {
+            crc ^= (byte as u16) << 8;
+            for _ in 0..8 {
+                crc = if crc & 0x8000 != 0 {
+                    (crc << 1) ^ 0x1021
+                } else {
+                    crc << 1
+                };
+            }
+        }
+        crc
+    }
+
+    /// Returns exactly `get_size()` booleans, one per bit, in index order.
+    ///
+    /// This is the inverse of collecting an iterator of `bool` into a `BitArray`.
+    pub fn to_bit_vec(&self) -> Vec<bool> {
+        (0..self.size).map(|i| self.get(i)).collect()
     }
 
     /**
@@ -362,6 +639,25 @@ impl BitArray {
         vec![0; (size + 31) / 32]
     }
 
+    /// The words holding bits `0..size`, with any bits past `size` in the last word masked off.
+    ///
+    /// The backing vector may hold additional capacity, which is never part of the array.
+    fn used_words(&self) -> impl Iterator<Item = u32> + '_ {
+        let words = self.size.div_ceil(32);
+        let last_mask = if self.size & 0x1F == 0 {
+            u32::MAX
+        } else {
+            (1 << (self.size & 0x1F)) - 1
+        };
+        self.bits[..words].iter().enumerate().map(move |(i, word)| {
+            if i + 1 == words {
+                word & last_mask
+            } else {
+                *word
+            }
+        })
+    }
+
     //   @Override
     //   public boolean equals(Object o) {
     //     if (!(o instanceof BitArray)) {
@@ -396,6 +692,41 @@ impl fmt::Display for BitArray {
     }
 }
 
+/// Two arrays are equal when they hold the same bits, regardless of how much capacity
+/// each one has reserved.
+impl PartialEq for BitArray {
+    fn eq(&self, other: &Self) -> bool {
+        self.size == other.size && self.used_words().eq(other.used_words())
+    }
+}
+
+impl Eq for BitArray {}
+
+impl Hash for BitArray {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.size.hash(state);
+        for word in self.used_words() {
+            word.hash(state);
+        }
+    }
+}
+
+impl FromIterator<bool> for BitArray {
+    fn from_iter<T: IntoIterator<Item = bool>>(iter: T) -> Self {
+        let mut array = BitArray::new();
+        array.extend(iter);
+        array
+    }
+}
+
+impl Extend<bool> for BitArray {
+    fn extend<T: IntoIterator<Item = bool>>(&mut self, iter: T) {
+        for bit in iter {
+            self.appendBit(bit);
+        }
+    }
+}
+
 impl Default for BitArray {
     fn default() -> Self {
         Self::new()
@@ -23,7 +23,10 @@ use std::fmt;
 use crate::common::Result;
 use crate::{Exceptions, Point};
 
-use super::BitArray;
+use super::{BitArray, Rect};
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
 
 /**
  * <p>Represents a 2D matrix of bits. In function arguments below, and throughout the common
@@ -40,6 +43,11 @@ use super::BitArray;
  * @author Sean Owen
  * @author dswitkin@google.com (Daniel Switkin)
  */
+#[cfg_attr(
+    feature = "serde",
+    derive(Serialize, Deserialize),
+    serde(try_from = "RawBitMatrix")
+)]
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct BitMatrix {
     width: u32,
@@ -82,6 +90,14 @@ impl BitMatrix {
         // bits = new int[rowSize * height];
     }
 
+    /// Returns an independent copy with the same dimensions, row size and bits.
+    ///
+    /// This is the same as `clone()`: the copy owns its own bits, so changes to either matrix
+    /// are never visible in the other.
+    pub fn deep_copy(&self) -> BitMatrix {
+        self.clone()
+    }
+
     #[allow(dead_code)]
     fn with_all_data(&self, width: u32, height: u32, rowSize: usize, bits: Vec<u32>) -> Self {
         Self {
@@ -127,25 +143,18 @@ impl BitMatrix {
         //   throw new IllegalArgumentException();
         // }
 
-        let mut bits = vec![false; string_representation.chars().count()];
+        // pos is a byte offset; it only ever advances past ASCII line breaks or whole set / unset
+        // strings, so it always stays on a char boundary.
+        let bytes = string_representation.as_bytes();
+        let mut bits = vec![false; string_representation.len()];
         let mut bitsPos = 0;
         let mut rowStartPos = 0;
         let mut rowLength = 0; //-1;
         let mut first_run = true;
         let mut nRows = 0;
         let mut pos = 0;
-        while pos < string_representation.chars().count() {
-            if string_representation
-                .chars()
-                .nth(pos)
-                .ok_or(Exceptions::ILLEGAL_STATE)?
-                == '\n'
-                || string_representation
-                    .chars()
-                    .nth(pos)
-                    .ok_or(Exceptions::ILLEGAL_STATE)?
-                    == '\r'
-            {
+        while pos < bytes.len() {
+            if bytes[pos] == b'\n' || bytes[pos] == b'\r' {
                 if bitsPos > rowStartPos {
                     //if rowLength == -1 {
                     if first_run {
@@ -201,6 +210,91 @@ impl BitMatrix {
         Ok(matrix)
     }
 
+    /// Unpacks a row-major bitmap of `width` x `height` bits, one bit per module, where each row
+    /// starts on a new byte. Within a byte, bits run from the most significant bit if
+    /// `msb_first`, else from the least significant bit.
+    pub fn from_packed_bytes(
+        width: u32,
+        height: u32,
+        bytes: &[u8],
+        msb_first: bool,
+    ) -> Result<Self> {
+        if width < 1 || height < 1 {
+            return Err(Exceptions::illegal_argument_with(
+                "Both dimensions must be greater than 0",
+            ));
+        }
+        // check the length before allocating, the dimensions may come from an untrusted header
+        let row_bytes = width.div_ceil(8) as usize;
+        let expected = row_bytes.checked_mul(height as usize);
+        if expected != Some(bytes.len()) {
+            return Err(Exceptions::illegal_argument_with(format!(
+                "{} bytes do not pack a {width}x{height} matrix",
+                bytes.len()
+            )));
+        }
+        let mut matrix = BitMatrix::new(width, height)?;
+        for (y, row) in bytes.chunks_exact(row_bytes).enumerate() {
+            for x in 0..width {
+                let shift = if msb_first { 7 - x % 8 } else { x % 8 };
+                if (row[x as usize / 8] >> shift) & 1 == 1 {
+                    matrix.set(x, y as u32);
+                }
+            }
+        }
+        Ok(matrix)
+    }
+
+    /// The version written by [`BitMatrix::to_packed`]; [`BitMatrix::from_packed`] reads only
+    /// this version.
+    pub const PACKED_FORMAT_VERSION: u8 = 1;
+
+    /// Serializes the matrix for storage: a version byte ([`BitMatrix::PACKED_FORMAT_VERSION`]),
+    /// the width and height as big-endian `u32`s, then the rows packed as read by
+    /// [`BitMatrix::from_packed_bytes`] with `msb_first`.
+    pub fn to_packed(&self) -> Vec<u8> {
+        let row_bytes = self.width.div_ceil(8) as usize;
+        let mut packed = Vec::with_capacity(9 + row_bytes * self.height as usize);
+        packed.push(Self::PACKED_FORMAT_VERSION);
+        packed.extend_from_slice(&self.width.to_be_bytes());
+        packed.extend_from_slice(&self.height.to_be_bytes());
+        for y in 0..self.height {
+            let start = packed.len();
+            packed.resize(start + row_bytes, 0);
+            for x in 0..self.width {
+                if self.get(x, y) {
+                    packed[start + x as usize / 8] |= 0x80 >> (x % 8);
+                }
+            }
+        }
+        packed
+    }
+
+    /// Reads a matrix written by [`BitMatrix::to_packed`], failing with a `FormatException` if
+    /// it was written in another version of the format or its header does not match its rows.
+    pub fn from_packed(bytes: &[u8]) -> Result<Self> {
+        let Some((&version, rest)) = bytes.split_first() else {
+            return Err(Exceptions::format_with("empty packed matrix"));
+        };
+        if version != Self::PACKED_FORMAT_VERSION {
+            return Err(Exceptions::format_with(format!(
+                "unsupported packed matrix version {version}"
+            )));
+        }
+        if rest.len() < 8 {
+            return Err(Exceptions::format_with("truncated packed matrix header"));
+        }
+        let (dimensions, rows) = rest.split_at(8);
+        let width =
+            u32::from_be_bytes([dimensions[0], dimensions[1], dimensions[2], dimensions[3]]);
+        let height =
+            u32::from_be_bytes([dimensions[4], dimensions[5], dimensions[6], dimensions[7]]);
+        Self::from_packed_bytes(width, height, rows, true).map_err(|e| match e {
+            Exceptions::IllegalArgumentException(msg) => Exceptions::FormatException(msg),
+            e => e,
+        })
+    }
+
     /**
      * <p>Gets the requested bit, where true means black.</p>
      *
@@ -310,12 +404,7 @@ impl BitMatrix {
      * @param mask XOR mask
      */
     pub fn xor(&mut self, mask: &BitMatrix) -> Result<()> {
-        if self.width != mask.width || self.height != mask.height || self.row_size != mask.row_size
-        {
-            return Err(Exceptions::illegal_argument_with(
-                "input matrix dimensions do not match",
-            ));
-        }
+        self.check_same_dimensions(mask)?;
         // let mut rowArray = BitArray::with_size(self.width as usize);
         for y in 0..self.height {
             //for (int y = 0; y < height; y++) {
@@ -331,6 +420,64 @@ impl BitMatrix {
         Ok(())
     }
 
+    /// Intersection (AND): unsets each bit in this matrix whose corresponding mask bit is unset.
+    pub fn and(&mut self, mask: &BitMatrix) -> Result<()> {
+        self.check_same_dimensions(mask)?;
+        for (word, mask_word) in self.bits.iter_mut().zip(&mask.bits) {
+            *word &= *mask_word;
+        }
+        Ok(())
+    }
+
+    /// Union (OR): sets each bit in this matrix whose corresponding mask bit is set.
+    pub fn or(&mut self, mask: &BitMatrix) -> Result<()> {
+        self.check_same_dimensions(mask)?;
+        for (word, mask_word) in self.bits.iter_mut().zip(&mask.bits) {
+            *word |= *mask_word;
+        }
+        Ok(())
+    }
+
+    /// Stamps the set bits of `other` onto this matrix with its top-left corner at (`x`, `y`).
+    ///
+    /// Each set bit is ORed in, or XORed in when `xor` is true. Bits unset in `other` leave this
+    /// matrix untouched. Fails if `other` would extend past the right or bottom edge.
+    pub fn overlay(&mut self, other: &BitMatrix, x: u32, y: u32, xor: bool) -> Result<()> {
+        let fits_x = x.checked_add(other.width).is_some_and(|r| r <= self.width);
+        let fits_y = y
+            .checked_add(other.height)
+            .is_some_and(|b| b <= self.height);
+        if !fits_x || !fits_y {
+            return Err(Exceptions::illegal_argument_with(format!(
+                "{}x{} matrix at ({x}, {y}) does not fit in {}x{} matrix",
+                other.width, other.height, self.width, self.height
+            )));
+        }
+        for oy in 0..other.height {
+            for ox in 0..other.width {
+                if !other.get(ox, oy) {
+                    continue;
+                }
+                if xor {
+                    self.flip_coords(x + ox, y + oy);
+                } else {
+                    self.set(x + ox, y + oy);
+                }
+            }
+        }
+        Ok(())
+    }
+
+    fn check_same_dimensions(&self, mask: &BitMatrix) -> Result<()> {
+        if self.width != mask.width || self.height != mask.height || self.row_size != mask.row_size
+        {
+            return Err(Exceptions::illegal_argument_with(
+                "input matrix dimensions do not match",
+            ));
+        }
+        Ok(())
+    }
+
     /**
      * Clears all bits (sets to false).
      */
@@ -357,6 +504,34 @@ impl BitMatrix {
         //         "Left and top must be nonnegative".to_owned(),
         //     ));
         // }
+        let (right, bottom) = self.region_bounds(left, top, width, height)?;
+        for y in top..bottom {
+            //for (int y = top; y < bottom; y++) {
+            let offset = y as usize * self.row_size;
+            for x in left..right {
+                //for (int x = left; x < right; x++) {
+                self.bits[offset + (x as usize / 32)] |= 1 << (x & 0x1f);
+            }
+        }
+        Ok(())
+    }
+
+    /// Clears a rectangular region of the bit matrix; the counterpart to `setRegion`, with the
+    /// same arguments and bounds checks.
+    pub fn unsetRegion(&mut self, left: u32, top: u32, width: u32, height: u32) -> Result<()> {
+        let (right, bottom) = self.region_bounds(left, top, width, height)?;
+        for y in top..bottom {
+            let offset = y as usize * self.row_size;
+            for x in left..right {
+                self.bits[offset + (x as usize / 32)] &= !(1 << (x & 0x1f));
+            }
+        }
+        Ok(())
+    }
+
+    /// Validates a region for `setRegion` / `unsetRegion`, returning its exclusive right and
+    /// bottom edges.
+    fn region_bounds(&self, left: u32, top: u32, width: u32, height: u32) -> Result<(u32, u32)> {
         if height < 1 || width < 1 {
             return Err(Exceptions::illegal_argument_with(
                 "height and width must be at least 1",
@@ -369,15 +544,7 @@ impl BitMatrix {
                 "the region must fit inside the matrix",
             ));
         }
-        for y in top..bottom {
-            //for (int y = top; y < bottom; y++) {
-            let offset = y as usize * self.row_size;
-            for x in left..right {
-                //for (int x = left; x < right; x++) {
-                self.bits[offset + (x as usize / 32)] |= 1 << (x & 0x1f);
-            }
-        }
-        Ok(())
+        Ok((right, bottom))
     }
 
     /**
@@ -405,17 +572,105 @@ impl BitMatrix {
             //for (int x = 0; x < rowSize; x++) {
             rw.setBulk(x * 32, self.bits[offset + x]);
         }
+        // don't carry padding bits past the width, e.g. left by flip_self, into the row
+        rw.setBulk(
+            (self.row_size - 1) * 32,
+            self.bits[offset + self.row_size - 1] & self.last_word_mask(),
+        );
         rw
     }
 
+    /// The bits of the last word of each row that lie within the width.
+    fn last_word_mask(&self) -> u32 {
+        match self.width % 32 {
+            0 => u32::MAX,
+            bits => (1 << bits) - 1,
+        }
+    }
+
+    /// Run-length encodes the set bits of row `y`.
+    ///
+    /// Returns one `(start, length)` pair per run of consecutive set bits, left to right.
+    pub fn row_runs(&self, y: u32) -> Vec<(u32, u32)> {
+        let row = self.getRow(y);
+        let mut runs = Vec::new();
+        let mut start = row.getNextSet(0);
+        while start < row.get_size() {
+            let end = row.getNextUnset(start);
+            runs.push((start as u32, (end - start) as u32));
+            start = row.getNextSet(end);
+        }
+        runs
+    }
+
+    /// Yields every `step`-th row, starting at row 0, along with its [`BitMatrix::row_runs`].
+    ///
+    /// This lets a 1D scanner try several scanlines without walking the bits itself.
+    /// A `step` of 0 is treated as 1.
+    pub fn one_d_rows(&self, step: u32) -> impl Iterator<Item = (u32, Vec<(u32, u32)>)> + '_ {
+        (0..self.height)
+            .step_by(step.max(1) as usize)
+            .map(|y| (y, self.row_runs(y)))
+    }
+
+    /// Yields each row, top to bottom, as a fresh [`BitArray`] of size `width`, see
+    /// [`BitMatrix::getRow`].
+    pub fn rows(&self) -> impl Iterator<Item = BitArray> + '_ {
+        (0..self.height).map(|y| self.getRow(y))
+    }
+
+    /// Whether every bit of row `y` in `[0, width)` equals `value`.
+    ///
+    /// Compares whole words; the padding bits past `width` in the last word are masked off.
+    pub fn row_is_uniform(&self, y: u32, value: bool) -> bool {
+        let offset = y as usize * self.row_size;
+        let expected = if value { u32::MAX } else { 0 };
+        let Some((last, full)) = self.bits[offset..offset + self.row_size].split_last() else {
+            return true;
+        };
+        full.iter().all(|word| *word == expected) && (last ^ expected) & self.last_word_mask() == 0
+    }
+
     /**
      * @param y row to set
-     * @param row {@link BitArray} to copy from
+     * @param row {@link BitArray} to copy from, at least as long as the matrix is wide. Bits
+     *  beyond the width of the matrix are ignored.
      */
-    pub fn setRow(&mut self, y: u32, row: &BitArray) {
-        self.bits[y as usize * self.row_size..y as usize * self.row_size + self.row_size]
-            .clone_from_slice(&row.getBitArray()[0..self.row_size])
+    pub fn setRow(&mut self, y: u32, row: &BitArray) -> Result<()> {
+        if y >= self.height {
+            return Err(Exceptions::illegal_argument_with(format!(
+                "row {y} is outside a matrix of height {}",
+                self.height
+            )));
+        }
+        if row.get_size() < self.width as usize {
+            return Err(Exceptions::illegal_argument_with(format!(
+                "row of size {} is shorter than the matrix width {}",
+                row.get_size(),
+                self.width
+            )));
+        }
+        self.set_row_unchecked(y, row);
+        Ok(())
+    }
+
+    /// Like [`BitMatrix::setRow`], but without validation: a `row` shorter than the matrix
+    /// leaves the rest of the matrix row unset.
+    ///
+    /// Panics if `y` is not less than the height of the matrix.
+    pub fn set_row_unchecked(&mut self, y: u32, row: &BitArray) {
+        let offset = y as usize * self.row_size;
+        let source = row.getBitArray();
+        for (x, word) in self.bits[offset..offset + self.row_size]
+            .iter_mut()
+            .enumerate()
+        {
+            *word = source.get(x).copied().unwrap_or(0);
+        }
         //System.arraycopy(row.getBitArray(), 0, self.bits, y * self.rowSize, self.rowSize);
+        if self.width & 0x1F != 0 && self.row_size > 0 {
+            self.bits[offset + self.row_size - 1] &= (1 << (self.width & 0x1F)) - 1;
+        }
     }
 
     /**
@@ -459,8 +714,8 @@ impl BitMatrix {
             let mut bottomRow = self.getRow(bottomRowIndex);
             topRow.reverse();
             bottomRow.reverse();
-            self.setRow(i, &bottomRow);
-            self.setRow(bottomRowIndex, &topRow);
+            self.set_row_unchecked(i, &bottomRow);
+            self.set_row_unchecked(bottomRowIndex, &topRow);
         }
     }
 
@@ -488,14 +743,98 @@ impl BitMatrix {
         self.height = newHeight;
         self.row_size = newRowSize as usize;
         self.bits = newBits;
+        self.assert_row_alignment();
+    }
+
+    /**
+     * Modifies this {@code BitMatrix} to swap its rows and columns, so that the bit at (x, y)
+     * moves to (y, x)
+     */
+    pub fn transpose(&mut self) {
+        let newWidth = self.height;
+        let newHeight = self.width;
+        let newRowSize = newWidth.div_ceil(32);
+        let mut newBits = vec![0; (newRowSize * newHeight) as usize];
+
+        for y in 0..self.height {
+            for x in 0..self.width {
+                let offset = self.get_offset(y, x);
+                if ((self.bits[offset] >> (x & 0x1f)) & 1) != 0 {
+                    let newOffset: usize = (x * newRowSize + (y / 32)) as usize;
+                    newBits[newOffset] |= 1 << (y & 0x1f);
+                }
+            }
+        }
+        self.width = newWidth;
+        self.height = newHeight;
+        self.row_size = newRowSize as usize;
+        self.bits = newBits;
+        self.assert_row_alignment();
+    }
+
+    /**
+     * Modifies this {@code BitMatrix} to represent the same but reflected left to right, so the
+     * first column becomes the last
+     */
+    pub fn mirror_horizontal(&mut self) {
+        for y in 0..self.height {
+            let mut row = self.getRow(y);
+            row.reverse();
+            self.set_row_unchecked(y, &row);
+        }
+    }
+
+    /**
+     * Modifies this {@code BitMatrix} to represent the same but reflected top to bottom, so the
+     * first row becomes the last
+     */
+    pub fn mirror_vertical(&mut self) {
+        for i in 0..self.height / 2 {
+            let topRow = self.getRow(i);
+            let bottomRowIndex = self.height - 1 - i;
+            let bottomRow = self.getRow(bottomRowIndex);
+            self.set_row_unchecked(i, &bottomRow);
+            self.set_row_unchecked(bottomRowIndex, &topRow);
+        }
+    }
+
+    /// Whether every row starts on a fresh `u32`, as described on [`BitMatrix`].
+    ///
+    /// This holds when the row size is exactly `ceil(width / 32)` words and the backing storage
+    /// is exactly `row_size * height` words, which is what consumers of [`BitMatrix::getRow`]
+    /// and the raw words rely on.
+    pub fn is_row_aligned(&self) -> bool {
+        self.row_size == self.width.div_ceil(32) as usize
+            && self.bits.len() == self.row_size * self.height as usize
+    }
+
+    /// Checks [`BitMatrix::is_row_aligned`] in debug builds; called after operations that
+    /// rebuild the backing storage.
+    fn assert_row_alignment(&self) {
+        debug_assert!(
+            self.is_row_aligned(),
+            "{}x{} matrix has row size {} and {} words",
+            self.width,
+            self.height,
+            self.row_size,
+            self.bits.len()
+        );
     }
 
     /**
      * This is useful in detecting the enclosing rectangle of a 'pure' barcode.
      *
      * @return {@code left,top,width,height} enclosing rectangle of all 1 bits, or null if it is all white
+     * @deprecated use {@link #enclosing_rectangle}
      */
+    #[deprecated]
     pub fn getEnclosingRectangle(&self) -> Option<[u32; 4]> {
+        self.enclosing_rectangle()
+            .map(|rect| [rect.left, rect.top, rect.width, rect.height])
+    }
+
+    /// The smallest [`Rect`] enclosing all set bits, or `None` if no bit is set.
+    pub fn enclosing_rectangle(&self) -> Option<Rect> {
         let mut left = self.width;
         let mut top = self.height;
         // let right = -1;
@@ -541,15 +880,22 @@ impl BitMatrix {
             return None;
         }
 
-        Some([left, top, right - left + 1, bottom - top + 1])
+        Some(Rect::new(left, top, right - left + 1, bottom - top + 1))
     }
 
     /**
      * This is useful in detecting a corner of a 'pure' barcode.
      *
      * @return {@code x,y} coordinate of top-left-most 1 bit, or null if it is all white
+     * @deprecated use {@link #top_left_on_bit}
      */
+    #[deprecated]
     pub fn getTopLeftOnBit(&self) -> Option<Vec<u32>> {
+        self.top_left_on_bit().map(|(x, y)| vec![x, y])
+    }
+
+    /// The `(x, y)` coordinate of the top-left-most set bit, or `None` if no bit is set.
+    pub fn top_left_on_bit(&self) -> Option<(u32, u32)> {
         let mut bitsOffset = 0;
         while bitsOffset < self.bits.len() && self.bits[bitsOffset] == 0 {
             bitsOffset += 1;
@@ -566,10 +912,19 @@ impl BitMatrix {
             bit += 1;
         }
         x += bit;
-        Some(vec![x as u32, y as u32])
+        Some((x as u32, y as u32))
     }
 
+    /**
+     * @deprecated use {@link #bottom_right_on_bit}
+     */
+    #[deprecated]
     pub fn getBottomRightOnBit(&self) -> Option<[u32; 2]> {
+        self.bottom_right_on_bit().map(|(x, y)| [x, y])
+    }
+
+    /// The `(x, y)` coordinate of the bottom-right-most set bit, or `None` if no bit is set.
+    pub fn bottom_right_on_bit(&self) -> Option<(u32, u32)> {
         let mut bitsOffset = self.bits.len() as i64 - 1;
         while bitsOffset >= 0 && self.bits[bitsOffset as usize] == 0 {
             bitsOffset -= 1;
@@ -588,7 +943,119 @@ impl BitMatrix {
         }
         x += bit;
 
-        Some([x as u32, y as u32])
+        Some((x as u32, y as u32))
+    }
+
+    /// The number of set bits in the matrix.
+    pub fn cardinality(&self) -> usize {
+        let padding = self.width & 0x1F;
+        if padding == 0 {
+            return self
+                .bits
+                .iter()
+                .map(|word| word.count_ones() as usize)
+                .sum();
+        }
+        // bits beyond the width (e.g. after flip()) must not be counted
+        let last_mask = (1u32 << padding) - 1;
+        self.bits
+            .chunks(self.row_size)
+            .map(|row| {
+                let (last, full) = row.split_last().unwrap_or((&0, &[]));
+                full.iter().map(|word| word.count_ones()).sum::<u32>()
+                    + (last & last_mask).count_ones()
+            })
+            .map(|count| count as usize)
+            .sum()
+    }
+
+    /// The proportion of set modules, from 0.0 for an all-white matrix to 1.0 for an all-black
+    /// one.
+    pub fn black_ratio(&self) -> f32 {
+        self.cardinality() as f32 / (self.width as f32 * self.height as f32)
+    }
+
+    /// Samples `num_samples` evenly spaced points on the line from (`x0`, `y0`) to (`x1`, `y1`),
+    /// both ends included, rounding each to the nearest pixel.
+    ///
+    /// Sampling a timing pattern at one point per module gives its alternating bits. A single
+    /// sample is taken at (`x0`, `y0`). Fails if either end lies outside the matrix.
+    pub fn sample_line(
+        &self,
+        x0: u32,
+        y0: u32,
+        x1: u32,
+        y1: u32,
+        num_samples: u32,
+    ) -> Result<BitArray> {
+        if x0.max(x1) >= self.width || y0.max(y1) >= self.height {
+            return Err(Exceptions::illegal_argument_with(format!(
+                "line ({x0}, {y0})-({x1}, {y1}) leaves the {}x{} matrix",
+                self.width, self.height
+            )));
+        }
+        let mut samples = BitArray::with_size(num_samples as usize);
+        let steps = num_samples.saturating_sub(1).max(1) as u64;
+        let lerp = |from: u32, to: u32, i: u64| {
+            ((from as u64 * (steps - i) + to as u64 * i + steps / 2) / steps) as u32
+        };
+        for i in 0..num_samples as u64 {
+            if self.get(lerp(x0, x1, i), lerp(y0, y1, i)) {
+                samples.set(i as usize);
+            }
+        }
+        Ok(samples)
+    }
+
+    /// Checks that the runs crossing (`cx`, `cy`) horizontally, vertically and along both
+    /// diagonals each follow the 1:1:3:1:1 black / white / black / white / black proportions of a
+    /// finder pattern centered there.
+    ///
+    /// `tolerance` is the allowed deviation of each run as a fraction of the module size, three
+    /// times that for the center run; ZXing's finder pattern search uses 0.5.
+    pub fn verify_finder_ratio(&self, cx: u32, cy: u32, tolerance: f32) -> bool {
+        if cx >= self.width || cy >= self.height || !self.get(cx, cy) {
+            return false;
+        }
+        [(1, 0), (0, 1), (1, 1), (1, -1)].iter().all(|&(dx, dy)| {
+            let counts = self.finder_runs(cx, cy, dx, dy);
+            if counts.contains(&0) {
+                return false;
+            }
+            let module_size = counts.iter().sum::<u32>() as f32 / 7.0;
+            let max_variance = module_size * tolerance;
+            counts
+                .iter()
+                .zip([1.0, 1.0, 3.0, 1.0, 1.0])
+                .all(|(count, modules)| {
+                    (modules * module_size - *count as f32).abs() <= modules * max_variance
+                })
+        })
+    }
+
+    /// The lengths of the black, white, black (through the center), white and black runs met
+    /// walking from (`cx`, `cy`) in both directions along (`dx`, `dy`).
+    fn finder_runs(&self, cx: u32, cy: u32, dx: i32, dy: i32) -> [u32; 5] {
+        let mut counts = [0; 5];
+        for (step, first_state, last_state) in [(-1, 2, 0), (1, 2, 4)] {
+            let (mut x, mut y) = (cx as i64, cy as i64);
+            let mut state = first_state;
+            while x >= 0 && y >= 0 && x < self.width as i64 && y < self.height as i64 {
+                // black runs have even states, white ones odd
+                if self.get(x as u32, y as u32) != (state % 2 == 0) {
+                    if state == last_state {
+                        break;
+                    }
+                    state = if step < 0 { state - 1 } else { state + 1 };
+                }
+                counts[state] += 1;
+                x += (step * dx) as i64;
+                y += (step * dy) as i64;
+            }
+        }
+        // the center module was counted by both walks
+        counts[2] -= 1;
+        counts
     }
 
     /**
@@ -612,6 +1079,12 @@ impl BitMatrix {
         self.row_size
     }
 
+    /// Approximately how many bytes this matrix occupies: the allocated capacity of its backing
+    /// words plus the struct itself.
+    pub fn heap_size_bytes(&self) -> usize {
+        std::mem::size_of::<Self>() + self.bits.capacity() * std::mem::size_of::<u32>()
+    }
+
     // @Override
     // public boolean equals(Object o) {
     //   if (!(o instanceof BitMatrix)) {
@@ -675,24 +1148,292 @@ impl BitMatrix {
     // public BitMatrix clone() {
     //   return new BitMatrix(width, height, rowSize, bits.clone());
     // }
-    // pub fn crop(&self, top:usize, left:usize, height: usize, width: usize) -> BitMatrix {
-    //     let area = self.bits.iter().skip(self.row_size * top).take(self.row_size * height)
-    //     .copied().collect::<Vec<u32>>();
-    //     let new_bits = area.chunks(self.row_size)
-    //     .skip(left).take(width).flatten().copied().collect::<Vec<u32>>();
-    //     Self { width: width, height: height, row_size: width, bits: () }
-    // }
-    pub fn crop(&self, top: usize, left: usize, height: usize, width: usize) -> BitMatrix {
-        let mut new_bm = BitMatrix::new(width as u32, height as u32).expect("create empty");
-        for y in top..top + height {
-            // let row = self.getRow(y as u32);
-            for x in left..left + width {
-                if self.get(x as u32, y as u32) {
-                    new_bm.set(x as u32, y as u32)
+    /// Copies the rectangle with top-left corner `(left, top)` and size `width` x `height` into
+    /// a new matrix.
+    ///
+    /// The arguments follow [`BitMatrix::getEnclosingRectangle`], so its result can be cropped
+    /// out directly. Fails if the rectangle is empty or not contained in this matrix.
+    pub fn crop(&self, left: u32, top: u32, width: u32, height: u32) -> Result<BitMatrix> {
+        let fits_x = left
+            .checked_add(width)
+            .is_some_and(|right| right <= self.width);
+        let fits_y = top
+            .checked_add(height)
+            .is_some_and(|bottom| bottom <= self.height);
+        if !fits_x || !fits_y {
+            return Err(Exceptions::illegal_argument_with(format!(
+                "{width}x{height} region at ({left},{top}) does not fit in a {}x{} matrix",
+                self.width, self.height
+            )));
+        }
+        let mut cropped = BitMatrix::new(width, height)?;
+        for y in 0..height {
+            for x in 0..width {
+                if self.get(left + x, top + y) {
+                    cropped.set(x, y);
+                }
+            }
+        }
+        cropped.assert_row_alignment();
+        Ok(cropped)
+    }
+
+    /// Renders this matrix with every module drawn as a `scale` x `scale` block, surrounded by
+    /// a `quiet_zone` modules wide unset border.
+    ///
+    /// The result is `(width + 2 * quiet_zone) * scale` by `(height + 2 * quiet_zone) * scale`.
+    /// Fails if `scale` is 0 or the result would not fit in `u32` dimensions.
+    pub fn scale_with_quiet_zone(&self, scale: u32, quiet_zone: u32) -> Result<BitMatrix> {
+        if scale == 0 {
+            return Err(Exceptions::illegal_argument_with(
+                "scale must be greater than 0",
+            ));
+        }
+        let scaled_dimension = |modules: u32| {
+            quiet_zone
+                .checked_mul(2)
+                .and_then(|border| border.checked_add(modules))
+                .and_then(|total| total.checked_mul(scale))
+                .ok_or_else(|| {
+                    Exceptions::illegal_argument_with(format!(
+                        "{modules} modules with a quiet zone of {quiet_zone} at scale {scale} is too large"
+                    ))
+                })
+        };
+        let mut scaled = BitMatrix::new(
+            scaled_dimension(self.width)?,
+            scaled_dimension(self.height)?,
+        )?;
+        for y in 0..self.height {
+            for x in 0..self.width {
+                if self.get(x, y) {
+                    scaled.setRegion(
+                        (x + quiet_zone) * scale,
+                        (y + quiet_zone) * scale,
+                        scale,
+                        scale,
+                    )?;
+                }
+            }
+        }
+        Ok(scaled)
+    }
+
+    /// Resizes this matrix to `width` x `height` using nearest-neighbor sampling.
+    ///
+    /// Output bit `(x, y)` is taken from `(x * self.width / width, y * self.height / height)`.
+    pub fn resize_nearest(&self, width: u32, height: u32) -> Result<BitMatrix> {
+        let mut resized = BitMatrix::new(width, height)?;
+        for y in 0..height {
+            let source_y = (y as u64 * self.height as u64 / height as u64) as u32;
+            for x in 0..width {
+                let source_x = (x as u64 * self.width as u64 / width as u64) as u32;
+                if self.get(source_x, source_y) {
+                    resized.set(x, y);
+                }
+            }
+        }
+        resized.assert_row_alignment();
+        Ok(resized)
+    }
+
+    /// Grows the set regions by one module in each of the 8 directions, `iterations` times.
+    ///
+    /// A bit becomes set if it or any of its 8-neighbors was set before the iteration.
+    pub fn dilate(&mut self, iterations: u32) {
+        for _ in 0..iterations {
+            self.apply_3x3(|neighborhood| neighborhood.iter().any(|set| *set));
+        }
+    }
+
+    /// Shrinks the set regions by one module in each of the 8 directions, `iterations` times.
+    ///
+    /// A bit stays set only if it and all of its 8-neighbors were set before the iteration;
+    /// positions outside the matrix count as unset. Eroding and then dilating (an opening)
+    /// removes speckle smaller than the structuring element while keeping larger shapes.
+    pub fn erode(&mut self, iterations: u32) {
+        for _ in 0..iterations {
+            self.apply_3x3(|neighborhood| neighborhood.iter().all(|set| *set));
+        }
+    }
+
+    /// Sets every bit 4-connected to (`x`, `y`) that has the seed's current color to `set`,
+    /// returning how many bits changed.
+    ///
+    /// Nothing changes if the seed already is `set`. Uses an explicit stack, so large regions
+    /// cannot overflow the call stack.
+    pub fn flood_fill(&mut self, x: u32, y: u32, set: bool) -> Result<u32> {
+        if x >= self.width || y >= self.height {
+            return Err(Exceptions::illegal_argument_with(format!(
+                "seed ({x}, {y}) lies outside the {}x{} matrix",
+                self.width, self.height
+            )));
+        }
+        let target = self.get(x, y);
+        if target == set {
+            return Ok(0);
+        }
+        let mut changed = 0;
+        let mut stack = vec![(x, y)];
+        while let Some((x, y)) = stack.pop() {
+            if self.get(x, y) != target {
+                continue;
+            }
+            self.set_bool(x, y, set);
+            changed += 1;
+            if x > 0 {
+                stack.push((x - 1, y));
+            }
+            if x + 1 < self.width {
+                stack.push((x + 1, y));
+            }
+            if y > 0 {
+                stack.push((x, y - 1));
+            }
+            if y + 1 < self.height {
+                stack.push((x, y + 1));
+            }
+        }
+        Ok(changed)
+    }
+
+    /// Replaces every bit by `rule` applied to its 3x3 neighborhood, read from a copy of the
+    /// matrix so that results of this pass do not feed back into it.
+    fn apply_3x3(&mut self, rule: impl Fn(&[bool; 9]) -> bool) {
+        let source = self.clone();
+        let get = |x: i64, y: i64| {
+            x >= 0
+                && y >= 0
+                && x < source.width as i64
+                && y < source.height as i64
+                && source.get(x as u32, y as u32)
+        };
+        for y in 0..self.height {
+            for x in 0..self.width {
+                let (cx, cy) = (x as i64, y as i64);
+                let neighborhood: [bool; 9] =
+                    std::array::from_fn(|i| get(cx + (i % 3) as i64 - 1, cy + (i / 3) as i64 - 1));
+                if rule(&neighborhood) {
+                    self.set(x, y);
+                } else {
+                    self.unset(x, y);
+                }
+            }
+        }
+    }
+
+    /// Builds an edge map of this matrix.
+    ///
+    /// A bit is set in the result iff the corresponding bit here is set and differs from at least
+    /// one of its 4-neighbors, i.e. has an unset neighbor. Positions outside the matrix count as
+    /// unset, so set bits along the border are always edges. For a filled region this yields its
+    /// outline, lying just inside the region.
+    pub fn edges(&self) -> BitMatrix {
+        let mut edges = BitMatrix::new(self.width, self.height).expect("dimensions already valid");
+        let neighbor = |x: i64, y: i64| -> bool {
+            x >= 0
+                && y >= 0
+                && x < self.width as i64
+                && y < self.height as i64
+                && self.get(x as u32, y as u32)
+        };
+        for y in 0..self.height {
+            for x in 0..self.width {
+                if !self.get(x, y) {
+                    continue;
+                }
+                let (x_i, y_i) = (x as i64, y as i64);
+                if !neighbor(x_i - 1, y_i)
+                    || !neighbor(x_i + 1, y_i)
+                    || !neighbor(x_i, y_i - 1)
+                    || !neighbor(x_i, y_i + 1)
+                {
+                    edges.set(x, y);
+                }
+            }
+        }
+        edges
+    }
+
+    /// Finds the 8-connected components of set bits.
+    ///
+    /// Returns the bounding box and the number of set bits of every component, in the order
+    /// their top-left-most bit is met when scanning row by row.
+    pub fn bounding_boxes_of_components(&self) -> Vec<(Rect, u32)> {
+        let mut visited =
+            BitMatrix::new(self.width, self.height).expect("dimensions already valid");
+        let mut components = Vec::new();
+        let mut stack = Vec::new();
+        for y in 0..self.height {
+            for x in 0..self.width {
+                if !self.get(x, y) || visited.get(x, y) {
+                    continue;
+                }
+                visited.set(x, y);
+                stack.push((x, y));
+                let (mut left, mut top, mut right, mut bottom) = (x, y, x, y);
+                let mut area = 0;
+                while let Some((cx, cy)) = stack.pop() {
+                    area += 1;
+                    left = left.min(cx);
+                    right = right.max(cx);
+                    top = top.min(cy);
+                    bottom = bottom.max(cy);
+                    for ny in cy.saturating_sub(1)..=(cy + 1).min(self.height - 1) {
+                        for nx in cx.saturating_sub(1)..=(cx + 1).min(self.width - 1) {
+                            if self.get(nx, ny) && !visited.get(nx, ny) {
+                                visited.set(nx, ny);
+                                stack.push((nx, ny));
+                            }
+                        }
+                    }
+                }
+                components.push((
+                    Rect::new(left, top, right - left + 1, bottom - top + 1),
+                    area,
+                ));
+            }
+        }
+        components
+    }
+
+    /// Bounding boxes of all connected components with at least `min_area` set bits.
+    ///
+    /// Each region can be cropped out and decoded on its own when looking for multiple barcodes.
+    pub fn candidate_regions(&self, min_area: u32) -> Vec<Rect> {
+        self.bounding_boxes_of_components()
+            .into_iter()
+            .filter(|(_, area)| *area >= min_area)
+            .map(|(region, _)| region)
+            .collect()
+    }
+
+    /// Samples an already rectified symbol without any perspective transform.
+    ///
+    /// This matrix must be exactly `modules_x` by `modules_y` equally sized module cells, the center
+    /// bit of each cell is read into the result. Returns an `IllegalArgumentException` when the
+    /// dimensions are not evenly divisible by the module counts.
+    pub fn sample_axis_aligned(&self, modules_x: u32, modules_y: u32) -> Result<BitMatrix> {
+        let module_width = self.width.checked_div(modules_x).unwrap_or(0);
+        let module_height = self.height.checked_div(modules_y).unwrap_or(0);
+        if module_width * modules_x != self.width || module_height * modules_y != self.height {
+            return Err(Exceptions::illegal_argument_with(format!(
+                "{}x{} matrix cannot be split into {modules_x}x{modules_y} modules",
+                self.width, self.height
+            )));
+        }
+        let mut sampled = BitMatrix::new(modules_x, modules_y)?;
+        for y in 0..modules_y {
+            for x in 0..modules_x {
+                if self.get(
+                    x * module_width + module_width / 2,
+                    y * module_height + module_height / 2,
+                ) {
+                    sampled.set(x, y);
                 }
             }
         }
-        new_bm
+        Ok(sampled)
     }
 
     pub fn isIn(&self, p: Point, b: i32) -> bool {
@@ -709,6 +1450,44 @@ impl fmt::Display for BitMatrix {
     }
 }
 
+/// The serialized fields of a [`BitMatrix`], checked for consistency before use.
+#[cfg(feature = "serde")]
+#[derive(Deserialize)]
+struct RawBitMatrix {
+    width: u32,
+    height: u32,
+    row_size: usize,
+    bits: Vec<u32>,
+}
+
+#[cfg(feature = "serde")]
+impl TryFrom<RawBitMatrix> for BitMatrix {
+    type Error = Exceptions;
+
+    fn try_from(value: RawBitMatrix) -> Result<Self, Self::Error> {
+        if value.row_size != value.width.div_ceil(32) as usize {
+            return Err(Exceptions::illegal_argument_with(format!(
+                "row size {} does not fit width {}",
+                value.row_size, value.width
+            )));
+        }
+        if value.bits.len() != value.row_size * value.height as usize {
+            return Err(Exceptions::illegal_argument_with(format!(
+                "{} words cannot hold {} rows of {}",
+                value.bits.len(),
+                value.height,
+                value.row_size
+            )));
+        }
+        Ok(Self {
+            width: value.width,
+            height: value.height,
+            row_size: value.row_size,
+            bits: value.bits,
+        })
+    }
+}
+
 #[cfg(feature = "image")]
 /// This should only be used if you *know* that the `DynamicImage` is binary.
 impl TryFrom<image::DynamicImage> for BitMatrix {
@@ -750,6 +1529,83 @@ impl From<&BitMatrix> for image::DynamicImage {
     }
 }
 
+#[cfg(feature = "image")]
+impl BitMatrix {
+    /// Renders the matrix at one pixel per module, using luminance `set` for set bits and
+    /// `unset` for the others.
+    pub fn to_gray_image(&self, set: u8, unset: u8) -> image::GrayImage {
+        image::GrayImage::from_fn(self.width, self.height, |x, y| {
+            image::Luma([if self.get(x, y) { set } else { unset }])
+        })
+    }
+
+    /// Renders the matrix as a black on white symbol, every module drawn as a `module_size` x
+    /// `module_size` block inside a quiet zone of `quiet_zone_modules` modules.
+    ///
+    /// See [`BitMatrix::scale_with_quiet_zone`] for the resulting dimensions and failure cases.
+    pub fn render_symbol(
+        &self,
+        module_size: u32,
+        quiet_zone_modules: u32,
+    ) -> Result<image::GrayImage> {
+        Ok(self
+            .scale_with_quiet_zone(module_size, quiet_zone_modules)?
+            .to_gray_image(0, u8::MAX))
+    }
+
+    /// Writes the output of [`BitMatrix::render_symbol`] to `path` as a PNG file.
+    pub fn save_png<P: AsRef<std::path::Path>>(
+        &self,
+        path: P,
+        module_size: u32,
+        quiet_zone_modules: u32,
+    ) -> Result<()> {
+        let path = path.as_ref();
+        self.render_symbol(module_size, quiet_zone_modules)?
+            .save_with_format(path, image::ImageFormat::Png)
+            .map_err(|err| {
+                Exceptions::writer_with(format!("could not save file '{}': {err}", path.display()))
+            })
+    }
+}
+
+#[cfg(feature = "bitvec")]
+impl BitMatrix {
+    /// Builds a matrix from `width * height` bits in row-major order, the layout produced by
+    /// converting a `BitMatrix` into a `BitVec`.
+    pub fn from_bitvec(bv: &bitvec::vec::BitVec, width: u32, height: u32) -> Result<Self> {
+        if bv.len() != width as usize * height as usize {
+            return Err(Exceptions::illegal_argument_with(format!(
+                "{} bits cannot fill a {width}x{height} matrix",
+                bv.len()
+            )));
+        }
+        let mut matrix = BitMatrix::new(width, height)?;
+        for index in bv.iter_ones() {
+            matrix.set(
+                (index % width as usize) as u32,
+                (index / width as usize) as u32,
+            );
+        }
+        Ok(matrix)
+    }
+}
+
+#[cfg(feature = "bitvec")]
+/// Flattens the matrix row by row into `width * height` bits.
+impl From<&BitMatrix> for bitvec::vec::BitVec {
+    fn from(value: &BitMatrix) -> Self {
+        let mut bv =
+            bitvec::vec::BitVec::with_capacity(value.width as usize * value.height as usize);
+        for y in 0..value.height {
+            for x in 0..value.width {
+                bv.push(value.get(x, y));
+            }
+        }
+        bv
+    }
+}
+
 #[cfg(feature = "svg_write")]
 impl From<&BitMatrix> for svg::Document {
     fn from(value: &BitMatrix) -> Self {
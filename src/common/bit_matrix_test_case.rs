@@ -27,7 +27,8 @@
 //  */
 // public final class BitMatrixTestCase extends Assert {
 
-use super::BitMatrix;
+use super::{BitArray, BitMatrix, Rect};
+use crate::Exceptions;
 
 static BIT_MATRIX_POINTS: [u32; 6] = [1, 2, 2, 0, 3, 1];
 
@@ -53,6 +54,335 @@ fn test_get_set() {
     }
 }
 
+#[test]
+fn test_black_ratio() {
+    let mut matrix = BitMatrix::new(33, 10).unwrap();
+    assert_eq!(0, matrix.cardinality());
+    assert_eq!(0.0, matrix.black_ratio());
+
+    matrix.setRegion(0, 0, 33, 5).unwrap();
+    assert_eq!(165, matrix.cardinality());
+    assert!((matrix.black_ratio() - 0.5).abs() < 1e-6);
+
+    // flipping must not count the padding bits beyond the width
+    matrix.flip_self();
+    assert_eq!(165, matrix.cardinality());
+    matrix.setRegion(0, 0, 33, 5).unwrap();
+    assert_eq!(1.0, matrix.black_ratio());
+}
+
+#[test]
+fn test_cardinality_of_full_matrix() {
+    // 45 is not a multiple of 32, so every row ends in a partially used word
+    let mut matrix = BitMatrix::new(45, 7).unwrap();
+    matrix.setRegion(0, 0, 45, 7).unwrap();
+    assert_eq!(45 * 7, matrix.cardinality());
+
+    // flipping also sets the padding bits beyond the width
+    let mut flipped = BitMatrix::new(45, 7).unwrap();
+    flipped.flip_self();
+    assert_eq!(45 * 7, flipped.cardinality());
+}
+
+#[test]
+fn test_verify_finder_ratio() {
+    let finder = BitMatrix::parse_strings(
+        "XXXXXXX\nX.....X\nX.XXX.X\nX.XXX.X\nX.XXX.X\nX.....X\nXXXXXXX\n",
+        "X",
+        ".",
+    )
+    .unwrap()
+    .scale_with_quiet_zone(3, 4)
+    .unwrap();
+    // the center module spans pixels 21..24
+    assert!(finder.verify_finder_ratio(22, 22, 0.5));
+    assert!(finder.verify_finder_ratio(21, 23, 0.5));
+    // white, and outside the matrix
+    assert!(!finder.verify_finder_ratio(17, 22, 0.5));
+    assert!(!finder.verify_finder_ratio(100, 22, 0.5));
+
+    let mut blob = BitMatrix::new(45, 45).unwrap();
+    let mut seed = 7_u32;
+    for y in 0..45 {
+        for x in 0..45 {
+            seed = seed.wrapping_mul(1_103_515_245).wrapping_add(12_345);
+            if (seed >> 16) & 1 == 1 {
+                blob.set(x, y);
+            }
+        }
+    }
+    blob.set(22, 22);
+    assert!(!blob.verify_finder_ratio(22, 22, 0.5));
+
+    let mut square = BitMatrix::new(45, 45).unwrap();
+    square.setRegion(10, 10, 25, 25).unwrap();
+    assert!(!square.verify_finder_ratio(22, 22, 0.5));
+}
+
+#[test]
+fn test_heap_size_bytes() {
+    // 2 words per row
+    let matrix = BitMatrix::new(33, 10).unwrap();
+    assert_eq!(
+        std::mem::size_of::<BitMatrix>() + 20 * std::mem::size_of::<u32>(),
+        matrix.heap_size_bytes()
+    );
+}
+
+#[test]
+fn test_clone_is_independent() {
+    let mut original = BitMatrix::new(70, 3).unwrap();
+    original.set(0, 0);
+    original.set(69, 2);
+
+    for mut copy in [original.clone(), original.deep_copy()] {
+        assert_eq!(original.getWidth(), copy.getWidth());
+        assert_eq!(original.getHeight(), copy.getHeight());
+        assert_eq!(original.getRowSize(), copy.getRowSize());
+        assert_eq!(original, copy);
+
+        copy.set(35, 1);
+        copy.unset(0, 0);
+        assert!(!original.get(35, 1));
+        assert!(original.get(0, 0));
+        assert_ne!(original, copy);
+    }
+
+    let copy = original.deep_copy();
+    original.set(1, 1);
+    assert!(!copy.get(1, 1));
+}
+
+#[test]
+fn test_from_packed_bytes() {
+    let bytes = [0b1000_0011, 0b0100_0000];
+    assert_eq!(
+        BitMatrix::parse_strings("X.....XX\n.X......\n", "X", ".").unwrap(),
+        BitMatrix::from_packed_bytes(8, 2, &bytes, true).unwrap()
+    );
+    assert_eq!(
+        BitMatrix::parse_strings("XX.....X\n......X.\n", "X", ".").unwrap(),
+        BitMatrix::from_packed_bytes(8, 2, &bytes, false).unwrap()
+    );
+
+    // 10 wide rows take 2 bytes each; the 6 bits past the width are ignored
+    let bytes = [0b1010_0000, 0xFF, 0b0000_0001, 0b0100_0000];
+    assert_eq!(
+        BitMatrix::parse_strings("X.X.....XX\n.......X.X\n", "X", ".").unwrap(),
+        BitMatrix::from_packed_bytes(10, 2, &bytes, true).unwrap()
+    );
+    assert_eq!(
+        BitMatrix::parse_strings(".....X.XXX\nX.........\n", "X", ".").unwrap(),
+        BitMatrix::from_packed_bytes(10, 2, &bytes, false).unwrap()
+    );
+
+    assert!(BitMatrix::from_packed_bytes(10, 2, &bytes[..3], true).is_err());
+    assert!(BitMatrix::from_packed_bytes(8, 2, &bytes, true).is_err());
+    assert!(BitMatrix::from_packed_bytes(0, 2, &bytes, true).is_err());
+}
+
+#[test]
+fn test_packed_format() {
+    let v1 = [
+        1,
+        0,
+        0,
+        0,
+        10,
+        0,
+        0,
+        0,
+        2,
+        0b1010_0000,
+        0xC0,
+        0b0000_0001,
+        0b0100_0000,
+    ];
+    let matrix = BitMatrix::from_packed(&v1).unwrap();
+    assert_eq!(
+        BitMatrix::parse_strings("X.X.....XX\n.......X.X\n", "X", ".").unwrap(),
+        matrix
+    );
+    assert_eq!(v1.as_slice(), matrix.to_packed());
+
+    let mut bumped = v1;
+    bumped[0] = BitMatrix::PACKED_FORMAT_VERSION + 1;
+    assert!(matches!(
+        BitMatrix::from_packed(&bumped),
+        Err(Exceptions::FormatException(_))
+    ));
+    assert!(matches!(
+        BitMatrix::from_packed(&[]),
+        Err(Exceptions::FormatException(_))
+    ));
+    assert!(matches!(
+        BitMatrix::from_packed(&v1[..6]),
+        Err(Exceptions::FormatException(_))
+    ));
+    // truncated row payload
+    assert!(matches!(
+        BitMatrix::from_packed(&v1[..12]),
+        Err(Exceptions::FormatException(_))
+    ));
+
+    // dimensions from the header are checked against the payload before allocating
+    for (width, height) in [(u32::MAX, 2), (70000, 70000), (u32::MAX, u32::MAX), (0, 2)] {
+        let mut header = vec![BitMatrix::PACKED_FORMAT_VERSION];
+        header.extend_from_slice(&width.to_be_bytes());
+        header.extend_from_slice(&height.to_be_bytes());
+        assert!(matches!(
+            BitMatrix::from_packed(&header),
+            Err(Exceptions::FormatException(_))
+        ));
+    }
+}
+
+#[test]
+fn test_crop() {
+    let matrix =
+        BitMatrix::parse_strings("......\n.XX.X.\n.X..X.\n..XXX.\n......\n", "X", ".").unwrap();
+    let rect = matrix.enclosing_rectangle().unwrap();
+    let cropped = matrix
+        .crop(rect.left, rect.top, rect.width, rect.height)
+        .unwrap();
+    assert_eq!(4, cropped.getWidth());
+    assert_eq!(3, cropped.getHeight());
+    for y in 0..3 {
+        for x in 0..4 {
+            assert_eq!(matrix.get(x + 1, y + 1), cropped.get(x, y), "({x},{y})");
+        }
+    }
+    assert_eq!(
+        BitMatrix::parse_strings("XX.X\nX..X\n.XXX\n", "X", ".").unwrap(),
+        cropped
+    );
+
+    assert_eq!(matrix, matrix.crop(0, 0, 6, 5).unwrap());
+    assert!(matrix.crop(3, 0, 4, 5).is_err());
+    assert!(matrix.crop(0, 1, 6, 5).is_err());
+    assert!(matrix.crop(0, 0, 0, 5).is_err());
+    assert!(matrix.crop(u32::MAX, 0, 2, 1).is_err());
+}
+
+#[test]
+fn test_scale_with_quiet_zone() {
+    let mut matrix = BitMatrix::new(3, 2).unwrap();
+    matrix.set(2, 1);
+
+    let scaled = matrix.scale_with_quiet_zone(4, 2).unwrap();
+    assert_eq!((3 + 2 * 2) * 4, scaled.getWidth());
+    assert_eq!((2 + 2 * 2) * 4, scaled.getHeight());
+    assert_eq!(16, scaled.cardinality());
+    assert_eq!(
+        Some(Rect::new((2 + 2) * 4, (1 + 2) * 4, 4, 4)),
+        scaled.enclosing_rectangle()
+    );
+
+    assert_eq!(matrix, matrix.scale_with_quiet_zone(1, 0).unwrap());
+    assert!(matrix.scale_with_quiet_zone(0, 2).is_err());
+    assert!(matrix.scale_with_quiet_zone(u32::MAX, 1).is_err());
+}
+
+#[test]
+fn test_row_alignment_after_reshaping() {
+    let mut matrix = BitMatrix::new(45, 7).unwrap();
+    matrix.set(0, 0);
+    matrix.set(44, 6);
+    assert!(matrix.is_row_aligned());
+
+    let cropped = matrix.crop(3, 1, 33, 6).unwrap();
+    assert!(cropped.is_row_aligned());
+    assert_eq!(matrix.get(35, 6), cropped.get(32, 5));
+
+    matrix.rotate90();
+    assert!(matrix.is_row_aligned());
+    assert_eq!(1, matrix.getRowSize());
+    matrix.rotate(270).unwrap();
+    assert!(matrix.is_row_aligned());
+    assert_eq!(2, matrix.getRowSize());
+
+    let resized = matrix.resize_nearest(90, 7).unwrap();
+    assert!(resized.is_row_aligned());
+    assert_eq!(3, resized.getRowSize());
+    assert!(resized.get(0, 0));
+    assert!(resized.get(1, 0));
+    assert!(!resized.get(2, 0));
+    assert!(resized.get(89, 6));
+    assert!(!resized.get(87, 6));
+}
+
+#[test]
+fn test_flood_fill() {
+    // a 4x3 white room inside a black border
+    let mut matrix = BitMatrix::parse_strings(
+        "........\n.XXXXXX.\n.X....X.\n.X....X.\n.X....XX\n.XXXXXX.\n",
+        "X",
+        ".",
+    )
+    .unwrap();
+    let border = matrix.clone();
+
+    assert_eq!(12, matrix.flood_fill(3, 2, true).unwrap());
+    assert_eq!(
+        BitMatrix::parse_strings(
+            "........\n.XXXXXX.\n.XXXXXX.\n.XXXXXX.\n.XXXXXXX\n.XXXXXX.\n",
+            "X",
+            "."
+        )
+        .unwrap(),
+        matrix
+    );
+    // already filled
+    assert_eq!(0, matrix.flood_fill(3, 2, true).unwrap());
+
+    // clearing the black region takes the border with it
+    assert_eq!(6 * 5 + 1, matrix.flood_fill(1, 1, false).unwrap());
+    assert_eq!(0, matrix.cardinality());
+
+    let mut outside = border.clone();
+    // all but the 19 border bits, the room and the walled off bottom right corner
+    assert_eq!(48 - 19 - 12 - 1, outside.flood_fill(0, 0, true).unwrap());
+    assert!(!outside.get(7, 5));
+    assert!(outside.flood_fill(8, 0, true).is_err());
+    assert!(outside.flood_fill(0, 6, true).is_err());
+
+    // a region far larger than recursion could handle
+    let mut large = BitMatrix::new(2000, 2000).unwrap();
+    assert_eq!(4_000_000, large.flood_fill(1999, 0, true).unwrap());
+}
+
+#[test]
+fn test_erode_dilate() {
+    let original = BitMatrix::parse_strings(
+        "..........\n.X........\n..........\n....XXXX..\n....XXXX..\n....XXXX..\n..........\n",
+        "X",
+        ".",
+    )
+    .unwrap();
+
+    let mut dilated = original.clone();
+    dilated.dilate(1);
+    assert_eq!(9 + 6 * 5, dilated.cardinality());
+
+    let mut opened = original.clone();
+    opened.erode(1);
+    assert_eq!(2, opened.cardinality());
+    opened.dilate(1);
+    assert_eq!(
+        BitMatrix::parse_strings(
+            "..........\n..........\n..........\n....XXXX..\n....XXXX..\n....XXXX..\n..........\n",
+            "X",
+            ".",
+        )
+        .unwrap(),
+        opened
+    );
+
+    let mut cleared = original;
+    cleared.erode(2);
+    assert_eq!(0, cleared.cardinality());
+}
+
 #[test]
 fn test_set_region() {
     let mut matrix = BitMatrix::with_single_dimension(5).expect("new");
@@ -70,6 +400,93 @@ fn test_set_region() {
 }
 
 #[test]
+fn test_unset_region() {
+    // spans a word boundary so the masks of two words per row are exercised
+    let mut matrix = BitMatrix::new(40, 10).unwrap();
+    matrix.setRegion(20, 1, 20, 8).expect("must set");
+    matrix.unsetRegion(28, 3, 8, 4).expect("must unset");
+    for y in 0..10 {
+        for x in 0..40 {
+            let set = (20..40).contains(&x) && (1..9).contains(&y);
+            let punched = (28..36).contains(&x) && (3..7).contains(&y);
+            assert_eq!(set && !punched, matrix.get(x, y), "({x}, {y})");
+        }
+    }
+
+    assert!(matrix.unsetRegion(0, 0, 0, 1).is_err());
+    assert!(matrix.unsetRegion(35, 0, 6, 1).is_err());
+    assert!(matrix.unsetRegion(0, 8, 1, 3).is_err());
+}
+
+#[test]
+fn test_sample_line() {
+    // a horizontal timing pattern of 3 pixel modules on row 6, from x = 6 to x = 29
+    let mut matrix = BitMatrix::new(40, 40).unwrap();
+    for module in (0..8).step_by(2) {
+        matrix.setRegion(6 + module * 3, 6, 3, 1).unwrap();
+    }
+    matrix.setRegion(7, 10, 1, 24).unwrap();
+
+    let alternating = |n: usize, first: usize| {
+        let mut bits = BitArray::with_size(n);
+        (first..n).step_by(2).for_each(|i| bits.set(i));
+        bits
+    };
+    // one sample in the middle of each module, in either direction
+    assert_eq!(
+        alternating(8, 0),
+        matrix.sample_line(7, 6, 28, 6, 8).unwrap()
+    );
+    assert_eq!(
+        alternating(8, 1),
+        matrix.sample_line(28, 6, 7, 6, 8).unwrap()
+    );
+    // a vertical line crossing the timing pattern
+    let vertical = matrix.sample_line(7, 4, 7, 34, 31).unwrap();
+    assert_eq!(31, vertical.get_size());
+    assert!(!vertical.get(0) && vertical.get(2) && !vertical.get(3) && vertical.get(6));
+    assert!(vertical.get(29) && !vertical.get(30));
+
+    assert_eq!(1, matrix.sample_line(6, 6, 39, 39, 1).unwrap().get_size());
+    assert!(matrix.sample_line(6, 6, 39, 39, 1).unwrap().get(0));
+    assert_eq!(0, matrix.sample_line(6, 6, 39, 39, 0).unwrap().get_size());
+
+    // ends outside the matrix
+    assert!(matrix.sample_line(7, 6, 40, 6, 8).is_err());
+    assert!(matrix.sample_line(7, 40, 7, 6, 8).is_err());
+}
+
+#[test]
+fn test_row_is_uniform() {
+    let mut matrix = BitMatrix::new(40, 4).unwrap();
+    assert!(matrix.row_is_uniform(0, false));
+    assert!(!matrix.row_is_uniform(0, true));
+
+    // all padding bits are set now; row 1 is cleared again and row 2 keeps one set bit
+    matrix.flip_self();
+    matrix.unsetRegion(0, 1, 40, 2).unwrap();
+    matrix.set(39, 2);
+    assert!(matrix.row_is_uniform(0, true));
+    assert!(matrix.row_is_uniform(1, false));
+    assert!(!matrix.row_is_uniform(2, false));
+    assert!(!matrix.row_is_uniform(2, true));
+
+    // data bits set, padding bits clear
+    let mut matrix = BitMatrix::new(40, 2).unwrap();
+    matrix.setRegion(0, 0, 40, 1).unwrap();
+    matrix.setRegion(1, 1, 39, 1).unwrap();
+    assert!(matrix.row_is_uniform(0, true));
+    assert!(!matrix.row_is_uniform(1, true));
+
+    let mut matrix = BitMatrix::new(64, 1).unwrap();
+    matrix.setRegion(0, 0, 64, 1).unwrap();
+    assert!(matrix.row_is_uniform(0, true));
+    matrix.unset(63, 0);
+    assert!(!matrix.row_is_uniform(0, true));
+}
+
+#[test]
+#[allow(deprecated)]
 fn test_enclosing() {
     let mut matrix = BitMatrix::with_single_dimension(5).expect("new");
     assert!(matrix.getEnclosingRectangle().is_none());
@@ -82,6 +499,18 @@ fn test_enclosing() {
 }
 
 #[test]
+#[allow(deprecated)]
+fn test_enclosing_rectangle() {
+    let mut matrix = BitMatrix::new(45, 40).unwrap();
+    assert_eq!(None, matrix.enclosing_rectangle());
+    matrix.setRegion(30, 5, 10, 20).unwrap();
+    matrix.set(34, 38);
+    assert_eq!(Some(Rect::new(30, 5, 10, 34)), matrix.enclosing_rectangle());
+    assert_eq!(Some([30, 5, 10, 34]), matrix.getEnclosingRectangle());
+}
+
+#[test]
+#[allow(deprecated)]
 fn test_on_bit() {
     let mut matrix = BitMatrix::with_single_dimension(5).expect("new");
     assert!(matrix.getTopLeftOnBit().is_none());
@@ -97,6 +526,23 @@ fn test_on_bit() {
     assert_eq!(vec![4, 4], matrix.getBottomRightOnBit().unwrap());
 }
 
+#[test]
+fn test_typed_corners_of_all_white_matrix() {
+    let matrix = BitMatrix::new(33, 7).unwrap();
+    assert_eq!(None, matrix.enclosing_rectangle());
+    assert_eq!(None, matrix.top_left_on_bit());
+    assert_eq!(None, matrix.bottom_right_on_bit());
+}
+
+#[test]
+fn test_typed_corners_of_single_bit() {
+    let mut matrix = BitMatrix::new(33, 7).unwrap();
+    matrix.set(32, 4);
+    assert_eq!(Some(Rect::new(32, 4, 1, 1)), matrix.enclosing_rectangle());
+    assert_eq!(Some((32, 4)), matrix.top_left_on_bit());
+    assert_eq!(Some((32, 4)), matrix.bottom_right_on_bit());
+}
+
 #[test]
 fn test_rectangular_matrix() {
     let mut matrix = BitMatrix::new(75, 20).unwrap();
@@ -177,6 +623,85 @@ fn test_get_row() {
     }
 }
 
+#[test]
+fn test_get_row_ignores_padding() {
+    // flipping sets the 29 padding bits past the width in the last word of every row
+    let mut matrix = BitMatrix::new(35, 2).unwrap();
+    matrix.flip_self();
+    matrix.unsetRegion(0, 0, 35, 1).unwrap();
+
+    let cleared = matrix.getRow(0);
+    assert_eq!(35, cleared.get_size());
+    assert_eq!(&[0, 0], cleared.getBitArray());
+    assert_eq!(BitArray::with_size(35), cleared);
+
+    let full = matrix.getRow(1);
+    assert_eq!(&[u32::MAX, 0b111], full.getBitArray());
+    let mut bytes = [0; 5];
+    full.toBytes(0, &mut bytes, 0, 5).unwrap();
+    assert_eq!([0xFF, 0xFF, 0xFF, 0xFF, 0b1110_0000], bytes);
+}
+
+#[test]
+fn test_rows() {
+    let matrix = BitMatrix::parse_strings(
+        "X....................................X\n.X..................................XX\nXX...................................X\n",
+        "X",
+        ".",
+    )
+    .unwrap();
+    let rows: Vec<BitArray> = matrix.rows().collect();
+    assert_eq!(3, rows.len());
+    assert!(rows.iter().all(|row| row.get_size() == 38));
+    assert_eq!(matrix.getRow(1), rows[1]);
+
+    let mut concatenated = BitArray::new();
+    for row in rows {
+        concatenated.appendBitArray(row);
+    }
+    assert_eq!(38 * 3, concatenated.get_size());
+    for y in 0..3 {
+        for x in 0..38 {
+            assert_eq!(
+                matrix.get(x, y),
+                concatenated.get((y * 38 + x) as usize),
+                "({x}, {y})"
+            );
+        }
+    }
+}
+
+#[test]
+fn test_set_row() {
+    let mut matrix = BitMatrix::new(1, 1).unwrap();
+    let mut row = BitArray::with_size(1);
+    row.set(0);
+    matrix.setRow(0, &row).unwrap();
+    assert!(matrix.get(0, 0));
+    assert_eq!(row, matrix.getRow(0));
+    assert!(matrix.setRow(1, &row).is_err());
+
+    // an undersized row is rejected, unless unchecked which leaves the rest of the row unset
+    let mut matrix = BitMatrix::new(70, 2).unwrap();
+    matrix.setRegion(0, 0, 70, 2).unwrap();
+    let mut row = BitArray::with_size(10);
+    row.set(3);
+    assert!(matrix.setRow(1, &row).is_err());
+    assert_eq!(70, matrix.getRow(1).iter_set_bits().count());
+    matrix.set_row_unchecked(1, &row);
+    assert_eq!(
+        vec![3],
+        matrix.getRow(1).iter_set_bits().collect::<Vec<_>>()
+    );
+    assert_eq!(70, matrix.getRow(0).iter_set_bits().count());
+
+    // bits of an oversized row beyond the matrix width are dropped
+    let mut row = BitArray::with_size(100);
+    row.setRange(60, 100).unwrap();
+    matrix.setRow(0, &row).unwrap();
+    assert_eq!(10, matrix.getRow(0).iter_set_bits().count());
+}
+
 #[test]
 fn test_rotate90_simple() {
     let mut matrix = BitMatrix::new(3, 3).unwrap();
@@ -217,6 +742,212 @@ fn test_rotate180_case() {
     test_rotate_180(8, 5);
 }
 
+#[test]
+fn test_rotate180_odd_widths() {
+    for (width, height) in [(17, 17), (33, 1), (40, 40), (32, 3), (65, 2)] {
+        let mut original = BitMatrix::new(width, height).unwrap();
+        for y in 0..height {
+            for x in 0..width {
+                if (x * 7 + y * 3) % 5 == 0 || x == width - 1 || y == 0 {
+                    original.set(x, y);
+                }
+            }
+        }
+
+        let mut rotated = original.clone();
+        rotated.rotate180();
+
+        for y in 0..height {
+            for x in 0..width {
+                assert_eq!(
+                    original.get(width - 1 - x, height - 1 - y),
+                    rotated.get(x, y),
+                    "{width}x{height} ({x},{y})"
+                );
+            }
+        }
+        // rotating back must restore the exact words, including the padding past the row width
+        rotated.rotate180();
+        assert_eq!(original, rotated, "{width}x{height}");
+    }
+}
+
+#[test]
+fn test_transpose() {
+    for (width, height) in [(5, 3), (33, 2), (1, 40)] {
+        let mut original = BitMatrix::new(width, height).unwrap();
+        for y in 0..height {
+            for x in 0..width {
+                if (x * 7 + y * 3) % 5 == 0 || x == width - 1 {
+                    original.set(x, y);
+                }
+            }
+        }
+
+        let mut transposed = original.clone();
+        transposed.transpose();
+        assert_eq!(height, transposed.getWidth());
+        assert_eq!(width, transposed.getHeight());
+        for y in 0..height {
+            for x in 0..width {
+                assert_eq!(
+                    original.get(x, y),
+                    transposed.get(y, x),
+                    "{width}x{height} ({x},{y})"
+                );
+            }
+        }
+
+        transposed.transpose();
+        assert_eq!(original, transposed, "{width}x{height}");
+    }
+}
+
+#[test]
+fn test_mirror() {
+    for (width, height) in [(5, 3), (33, 4), (40, 7)] {
+        let mut original = BitMatrix::new(width, height).unwrap();
+        for y in 0..height {
+            for x in 0..width {
+                if (x * 7 + y * 3) % 5 == 0 || x == width - 1 || y == 0 {
+                    original.set(x, y);
+                }
+            }
+        }
+
+        let mut horizontal = original.clone();
+        horizontal.mirror_horizontal();
+        let mut vertical = original.clone();
+        vertical.mirror_vertical();
+        for y in 0..height {
+            for x in 0..width {
+                assert_eq!(
+                    original.get(width - 1 - x, y),
+                    horizontal.get(x, y),
+                    "{width}x{height} ({x},{y})"
+                );
+            }
+            assert_eq!(
+                original.getRow(height - 1 - y),
+                vertical.getRow(y),
+                "{width}x{height} row {y}"
+            );
+        }
+        assert_ne!(original, horizontal);
+        assert_ne!(original, vertical);
+
+        horizontal.mirror_horizontal();
+        assert_eq!(original, horizontal, "{width}x{height}");
+        vertical.mirror_vertical();
+        assert_eq!(original, vertical, "{width}x{height}");
+
+        // both mirrors together are a rotation by 180 degrees
+        let mut rotated = original.clone();
+        rotated.rotate180();
+        horizontal.mirror_horizontal();
+        horizontal.mirror_vertical();
+        assert_eq!(rotated, horizontal, "{width}x{height}");
+    }
+}
+
+#[test]
+fn test_edges() {
+    let mut matrix = BitMatrix::new(40, 10).unwrap();
+    matrix.setRegion(30, 2, 6, 5).unwrap();
+    let edges = matrix.edges();
+    for y in 0..10 {
+        for x in 0..40 {
+            let on_perimeter = (x == 30 || x == 35) && (2..7).contains(&y)
+                || (y == 2 || y == 6) && (30..36).contains(&x);
+            assert_eq!(on_perimeter, edges.get(x, y), "({x},{y})");
+        }
+    }
+
+    let mut full = BitMatrix::new(3, 3).unwrap();
+    full.setRegion(0, 0, 3, 3).unwrap();
+    let mut ring = full.clone();
+    ring.unset(1, 1);
+    assert_eq!(ring, full.edges());
+
+    assert_eq!(
+        BitMatrix::new(3, 3).unwrap(),
+        BitMatrix::new(3, 3).unwrap().edges()
+    );
+}
+
+#[test]
+fn test_one_d_rows() {
+    let mut matrix = BitMatrix::new(40, 5).unwrap();
+    for y in 0..5 {
+        // bars of width 1, 2 and 3, the last one crossing a word boundary
+        matrix.set(0, y);
+        matrix.setRegion(4, y, 2, 1).unwrap();
+        matrix.setRegion(31, y, 3, 1).unwrap();
+    }
+    // a row which looks different, should be skipped with step 2
+    matrix.setRegion(10, 1, 30, 1).unwrap();
+
+    let rows: Vec<(u32, Vec<(u32, u32)>)> = matrix.one_d_rows(2).collect();
+    let expected_runs = vec![(0, 1), (4, 2), (31, 3)];
+    assert_eq!(
+        vec![
+            (0, expected_runs.clone()),
+            (2, expected_runs.clone()),
+            (4, expected_runs)
+        ],
+        rows
+    );
+
+    assert_eq!(vec![(0, 1), (4, 2), (10, 30)], matrix.row_runs(1));
+    assert!(BitMatrix::new(3, 3).unwrap().row_runs(0).is_empty());
+}
+
+#[test]
+fn test_candidate_regions() {
+    let mut matrix = BitMatrix::new(100, 60).unwrap();
+    matrix.setRegion(5, 5, 30, 30).unwrap();
+    // a diagonal tail still belongs to the first blob
+    matrix.set(35, 35);
+    matrix.setRegion(50, 10, 40, 45).unwrap();
+    matrix.setRegion(2, 50, 2, 2).unwrap();
+
+    assert_eq!(
+        vec![Rect::new(5, 5, 31, 31), Rect::new(50, 10, 40, 45)],
+        matrix.candidate_regions(100)
+    );
+    assert_eq!(
+        vec![
+            (Rect::new(5, 5, 31, 31), 901),
+            (Rect::new(50, 10, 40, 45), 1800),
+            (Rect::new(2, 50, 2, 2), 4)
+        ],
+        matrix.bounding_boxes_of_components()
+    );
+    assert!(BitMatrix::new(5, 5)
+        .unwrap()
+        .candidate_regions(0)
+        .is_empty());
+}
+
+#[test]
+fn test_sample_axis_aligned() {
+    let modules = BitMatrix::parse_strings("X.X.X\n.XX..\nX...X\n", "X", ".").unwrap();
+    let mut upsampled = BitMatrix::new(15, 9).unwrap();
+    for y in 0..upsampled.getHeight() {
+        for x in 0..upsampled.getWidth() {
+            if modules.get(x / 3, y / 3) {
+                upsampled.set(x, y);
+            }
+        }
+    }
+    assert_eq!(modules, upsampled.sample_axis_aligned(5, 3).unwrap());
+    assert_eq!(upsampled, upsampled.sample_axis_aligned(15, 9).unwrap());
+
+    assert!(upsampled.sample_axis_aligned(4, 3).is_err());
+    assert!(upsampled.sample_axis_aligned(5, 2).is_err());
+    assert!(upsampled.sample_axis_aligned(0, 3).is_err());
+}
+
 #[test]
 fn test_parse() {
     let emptyMatrix = BitMatrix::new(3, 3).unwrap();
@@ -266,6 +997,38 @@ fn test_parse() {
     );
 }
 
+#[test]
+fn test_parse_large() {
+    let mut matrix = BitMatrix::new(200, 200).unwrap();
+    for y in 0..200 {
+        for x in 0..200 {
+            if (x * y) % 7 == 0 {
+                matrix.set(x, y);
+            }
+        }
+    }
+    let parsed = BitMatrix::parse_strings(&matrix.toString("X ", "  "), "X ", "  ").unwrap();
+    assert_eq!(matrix, parsed);
+}
+
+#[test]
+fn test_parse_multi_byte() {
+    let mut centerMatrix = BitMatrix::new(3, 3).unwrap();
+    centerMatrix.setRegion(1, 1, 1, 1).expect("must set");
+
+    assert_eq!(
+        centerMatrix,
+        BitMatrix::parse_strings("···\n·█·\n···\n", "█", "·").unwrap()
+    );
+    assert_eq!(
+        centerMatrix,
+        BitMatrix::parse_strings("░░░░░░\n░░██░░\n░░░░░░", "██", "░░").unwrap()
+    );
+
+    assert!(BitMatrix::parse_strings("···\n·█·\n··\n", "█", "·").is_err());
+    assert!(BitMatrix::parse_strings("···\n·é·\n···\n", "█", "·").is_err());
+}
+
 #[test]
 fn test_parse_boolean() {
     let emptyMatrix = BitMatrix::new(3, 3).unwrap();
@@ -342,6 +1105,150 @@ fn test_xor_case() {
     // }
 }
 
+#[test]
+fn test_and_or() {
+    let left = BitMatrix::parse_strings("XX..\nX.X.\n", "X", ".").unwrap();
+    let right = BitMatrix::parse_strings("X.X.\n..XX\n", "X", ".").unwrap();
+
+    let mut and = left.clone();
+    and.and(&right).expect("must combine");
+    assert_eq!(
+        BitMatrix::parse_strings("X...\n..X.\n", "X", ".").unwrap(),
+        and
+    );
+
+    let mut or = left.clone();
+    or.or(&right).expect("must combine");
+    assert_eq!(
+        BitMatrix::parse_strings("XXX.\nX.XX\n", "X", ".").unwrap(),
+        or
+    );
+
+    let badMatrix = BitMatrix::new(4, 3).unwrap();
+    assert!(left.clone().and(&badMatrix).is_err());
+    assert!(left.clone().or(&badMatrix).is_err());
+}
+
+#[test]
+fn test_overlay() {
+    let stamp = BitMatrix::parse_strings("XX.\n.X.\nX.X\n", "X", ".").unwrap();
+
+    let mut blank = BitMatrix::new(3, 3).unwrap();
+    blank.overlay(&stamp, 0, 0, false).expect("must fit");
+    assert_eq!(stamp, blank);
+
+    let original = BitMatrix::parse_strings("X...X\n.X...\n..X..\n...X.\n", "X", ".").unwrap();
+    let mut target = original.clone();
+    target.overlay(&stamp, 1, 1, false).expect("must fit");
+    assert_eq!(
+        BitMatrix::parse_strings("X...X\n.XX..\n..X..\n.X.X.\n", "X", ".").unwrap(),
+        target
+    );
+
+    let mut target = original.clone();
+    target.overlay(&stamp, 2, 1, true).expect("must fit");
+    assert_ne!(original, target);
+    target.overlay(&stamp, 2, 1, true).expect("must fit");
+    assert_eq!(original, target);
+
+    assert!(target.overlay(&stamp, 3, 0, false).is_err());
+    assert!(target.overlay(&stamp, 0, 2, true).is_err());
+    assert!(target.overlay(&stamp, u32::MAX, 0, false).is_err());
+    assert_eq!(original, target);
+}
+
+#[cfg(feature = "image")]
+#[test]
+fn test_to_gray_image() {
+    let matrix = BitMatrix::parse_strings("X..X.\n.XX..\n....X\n", "X", ".").unwrap();
+    let image = matrix.to_gray_image(20, 230);
+    assert_eq!((5, 3), image.dimensions());
+    assert_eq!(20, image.get_pixel(0, 0).0[0]);
+    assert_eq!(230, image.get_pixel(1, 0).0[0]);
+
+    let mut rebuilt = BitMatrix::new(image.width(), image.height()).unwrap();
+    for (x, y, pixel) in image.enumerate_pixels() {
+        if pixel.0[0] < 128 {
+            rebuilt.set(x, y);
+        }
+    }
+    assert_eq!(matrix, rebuilt);
+}
+
+#[cfg(feature = "image")]
+#[test]
+fn test_render_symbol() {
+    let matrix = BitMatrix::parse_strings("X.X\n.X.\nX..\n", "X", ".").unwrap();
+    let image = matrix.render_symbol(2, 4).unwrap();
+    // 3 modules plus a 4 module quiet zone on each side, 2 pixels per module
+    assert_eq!((22, 22), image.dimensions());
+    assert_eq!(u8::MAX, image.get_pixel(7, 7).0[0]);
+    assert_eq!(0, image.get_pixel(8, 8).0[0]);
+    assert_eq!(0, image.get_pixel(9, 9).0[0]);
+    assert_eq!(u8::MAX, image.get_pixel(10, 8).0[0]);
+    assert!(matrix.render_symbol(0, 4).is_err());
+
+    let path = std::env::temp_dir().join(format!(
+        "rxing_test_render_symbol_{}.png",
+        std::process::id()
+    ));
+    matrix.save_png(&path, 2, 4).unwrap();
+    let saved = image::open(&path).unwrap().into_luma8();
+    assert_eq!(image, saved);
+    std::fs::remove_file(&path).unwrap();
+
+    let missing_dir = path.with_extension("").join("symbol.png");
+    assert!(matches!(
+        matrix.save_png(missing_dir, 2, 4),
+        Err(Exceptions::WriterException(_))
+    ));
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn test_serde_round_trip() {
+    let matrix = BitMatrix::parse_strings("X..X.\n.XX..\n....X\n", "X", ".").unwrap();
+    let json = serde_json::to_string(&matrix).unwrap();
+    assert_eq!(
+        r#"{"width":5,"height":3,"row_size":1,"bits":[9,6,16]}"#,
+        json
+    );
+    assert_eq!(matrix, serde_json::from_str::<BitMatrix>(&json).unwrap());
+
+    let row = matrix.getRow(1);
+    let json = serde_json::to_string(&row).unwrap();
+    assert_eq!(row, serde_json::from_str::<BitArray>(&json).unwrap());
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn test_serde_rejects_tampered_lengths() {
+    // one word short of three rows
+    assert!(serde_json::from_str::<BitMatrix>(
+        r#"{"width":5,"height":3,"row_size":1,"bits":[9,6]}"#
+    )
+    .is_err());
+    // row_size does not match the width
+    assert!(serde_json::from_str::<BitMatrix>(
+        r#"{"width":40,"height":1,"row_size":1,"bits":[9]}"#
+    )
+    .is_err());
+    assert!(serde_json::from_str::<BitArray>(r#"{"bits":[1],"size":33}"#).is_err());
+}
+
+#[cfg(feature = "bitvec")]
+#[test]
+fn test_bitvec_round_trip() {
+    let matrix = BitMatrix::parse_strings("X..X.\n.XX..\n....X\n", "X", ".").unwrap();
+    let bv = bitvec::vec::BitVec::from(&matrix);
+    assert_eq!(15, bv.len());
+    assert_eq!(vec![0, 3, 6, 7, 14], bv.iter_ones().collect::<Vec<_>>());
+    assert_eq!(matrix, BitMatrix::from_bitvec(&bv, 5, 3).unwrap());
+
+    assert!(BitMatrix::from_bitvec(&bv, 4, 3).is_err());
+    assert!(BitMatrix::from_bitvec(&bv, 5, 4).is_err());
+}
+
 pub fn matrix_to_string(result: &BitMatrix) -> String {
     assert_eq!(1, result.getHeight());
     let mut builder = String::with_capacity(result.getWidth().try_into().unwrap());
@@ -34,6 +34,7 @@ pub struct BitSource {
     bytes: Vec<u8>,
     byte_offset: usize,
     bit_offset: usize,
+    checksum: Option<u32>,
 }
 
 impl BitSource {
@@ -46,9 +47,36 @@ impl BitSource {
             bytes,
             byte_offset: 0,
             bit_offset: 0,
+            checksum: None,
         }
     }
 
+    /// Creates a `BitSource` which also keeps a running checksum of every byte it has fully consumed.
+    ///
+    /// The checksum is the wrapping (mod 2^32) sum of the unsigned values of the consumed bytes.
+    /// A byte counts as consumed once its last bit has been read, see [`BitSource::consumed_checksum`].
+    pub fn with_checksum(bytes: Vec<u8>) -> Self {
+        Self {
+            checksum: Some(0),
+            ..Self::new(bytes)
+        }
+    }
+
+    /// Returns the wrapping sum of all bytes fully consumed so far.
+    ///
+    /// Always returns 0 if this source was not created with [`BitSource::with_checksum`].
+    pub fn consumed_checksum(&self) -> u32 {
+        self.checksum.unwrap_or(0)
+    }
+
+    /// Moves on to the next byte, folding the finished byte into the checksum if one is tracked.
+    fn advance_byte(&mut self) {
+        if let Some(checksum) = self.checksum.as_mut() {
+            *checksum = checksum.wrapping_add(self.bytes[self.byte_offset] as u32);
+        }
+        self.byte_offset += 1;
+    }
+
     /**
      * @return index of next bit in current byte which would be read by the next call to {@link #readBits(int)}.
      */
@@ -70,6 +98,35 @@ impl BitSource {
      * @throws IllegalArgumentException if numBits isn't in [1,32] or more than is available
      */
     pub fn readBits(&mut self, numBits: usize) -> Result<u32> {
+        let (result, byte_offset, bit_offset) = self.read_bits_at(numBits)?;
+        while self.byte_offset < byte_offset {
+            self.advance_byte();
+        }
+        self.bit_offset = bit_offset;
+        Ok(result)
+    }
+
+    /// Reads the next bit, true if it is set.
+    pub fn read_bit(&mut self) -> Result<bool> {
+        Ok(self.readBits(1)? == 1)
+    }
+
+    /// Skips the rest of the current byte, if it has been partially read.
+    pub fn align_to_byte(&mut self) {
+        if self.bit_offset > 0 {
+            self.bit_offset = 0;
+            self.advance_byte();
+        }
+    }
+
+    /// Returns the value [`BitSource::readBits`] would, without consuming any bits.
+    pub fn peek_bits(&self, num_bits: usize) -> Result<u32> {
+        self.read_bits_at(num_bits).map(|(result, _, _)| result)
+    }
+
+    /// Reads `numBits` from the current position, returning them with the byte and bit offsets
+    /// just past them. The source itself is left untouched.
+    fn read_bits_at(&self, numBits: usize) -> Result<(u32, usize, usize)> {
         if !(1..=32).contains(&numBits) || numBits > self.available() {
             return Err(Exceptions::illegal_argument_with(numBits.to_string()));
         }
@@ -77,29 +134,31 @@ impl BitSource {
         let mut result: u32 = 0;
 
         let mut num_bits = numBits;
+        let mut byte_offset = self.byte_offset;
+        let mut bit_offset = self.bit_offset;
 
         // First, read remainder from current byte
-        if self.bit_offset > 0 {
-            let bitsLeft = 8 - self.bit_offset;
+        if bit_offset > 0 {
+            let bitsLeft = 8 - bit_offset;
             let toRead = cmp::min(num_bits, bitsLeft);
             let bitsToNotRead = bitsLeft - toRead;
             let mask = (0xFF >> (8 - toRead)) << bitsToNotRead;
 
-            result = (self.bytes[self.byte_offset] & mask) as u32 >> bitsToNotRead;
+            result = (self.bytes[byte_offset] & mask) as u32 >> bitsToNotRead;
             num_bits -= toRead;
-            self.bit_offset += toRead;
-            if self.bit_offset == 8 {
-                self.bit_offset = 0;
-                self.byte_offset += 1;
+            bit_offset += toRead;
+            if bit_offset == 8 {
+                bit_offset = 0;
+                byte_offset += 1;
             }
         }
 
         // Next read whole bytes
         if num_bits > 0 {
             while num_bits >= 8 {
-                result = (result << 8) | self.bytes[self.byte_offset] as u32;
+                result = (result << 8) | self.bytes[byte_offset] as u32;
                 // result = ((result as u16) << 8) as u8 | (self.bytes[self.byte_offset]);
-                self.byte_offset += 1;
+                byte_offset += 1;
                 num_bits -= 8;
             }
 
@@ -108,14 +167,53 @@ impl BitSource {
                 let bits_to_not_read = 8 - num_bits;
                 let mask = (0xFF >> bits_to_not_read) << bits_to_not_read;
                 result = (result << num_bits)
-                    | ((self.bytes[self.byte_offset] & mask) as u32 >> bits_to_not_read);
-                self.bit_offset += num_bits;
+                    | ((self.bytes[byte_offset] & mask) as u32 >> bits_to_not_read);
+                bit_offset += num_bits;
             }
         }
 
+        Ok((result, byte_offset, bit_offset))
+    }
+
+    /// Reads the next `n` whole bytes, advancing the byte offset past them. Fails if the source
+    /// is partway through a byte or fewer than `n` bytes remain.
+    pub fn read_bytes(&mut self, n: usize) -> Result<Vec<u8>> {
+        if self.bit_offset != 0 {
+            return Err(Exceptions::illegal_state_with(format!(
+                "cannot read whole bytes at bit offset {} of the current byte",
+                self.bit_offset
+            )));
+        }
+        let remaining = self.bytes.len() - self.byte_offset;
+        if n > remaining {
+            return Err(Exceptions::illegal_argument_with(format!(
+                "{n} bytes requested but only {remaining} available"
+            )));
+        }
+        let result = self.bytes[self.byte_offset..self.byte_offset + n].to_vec();
+        for _ in 0..n {
+            self.advance_byte();
+        }
         Ok(result)
     }
 
+    /// Yields the remaining bits one at a time, most significant bit first, until the source is
+    /// exhausted. Each bit taken from the iterator advances the offsets just like `readBits(1)`.
+    pub fn bits(&mut self) -> impl Iterator<Item = bool> + '_ {
+        std::iter::from_fn(move || {
+            if self.byte_offset >= self.bytes.len() {
+                return None;
+            }
+            let bit = self.bytes[self.byte_offset] & (0x80 >> self.bit_offset) != 0;
+            self.bit_offset += 1;
+            if self.bit_offset == 8 {
+                self.bit_offset = 0;
+                self.advance_byte();
+            }
+            Some(bit)
+        })
+    }
+
     /**
      * @return number of bits that can be read successfully
      */
@@ -101,10 +101,14 @@ impl CharacterSet {
     //     }
     // }
 
-    fn get_base_encoder(&self) -> EncodingRef {
+    /// Looks up the `encoding` crate implementation for this character set.
+    ///
+    /// Not every legal character set is available there (e.g. UTF-32), in which case a
+    /// `UnsupportedOperationException` is returned instead of panicking.
+    fn get_base_encoder(&self) -> Result<EncodingRef> {
         let name = match self {
             CharacterSet::Cp437 => "cp437",
-            CharacterSet::ISO8859_1 => return encoding::all::ISO_8859_1,
+            CharacterSet::ISO8859_1 => return Ok(encoding::all::ISO_8859_1),
             CharacterSet::ISO8859_2 => "ISO-8859-2",
             CharacterSet::ISO8859_3 => "ISO-8859-3",
             CharacterSet::ISO8859_4 => "ISO-8859-4",
@@ -137,7 +141,18 @@ impl CharacterSet {
             CharacterSet::Binary => "binary",
             CharacterSet::Unknown => "unknown",
         };
-        encoding::label::encoding_from_whatwg_label(name).unwrap()
+        encoding::label::encoding_from_whatwg_label(name).ok_or_else(|| {
+            Exceptions::unsupported_operation_with(format!("character set {name} is not available"))
+        })
+    }
+
+    /// Like [`CharacterSet::get_base_encoder`], but GB2312 text is decoded with its superset
+    /// GB18030, which is what ECI 29 designates.
+    fn get_base_decoder(&self) -> Result<EncodingRef> {
+        match self {
+            CharacterSet::GB2312 => CharacterSet::GB18030.get_base_encoder(),
+            _ => self.get_base_encoder(),
+        }
     }
 
     pub fn get_charset_name(&self) -> &'static str {
@@ -178,6 +193,91 @@ impl CharacterSet {
         }
     }
 
+    /// All ECI values designating this character set, in ascending order, like the `values`
+    /// of Java's `CharacterSetECI`.
+    ///
+    /// Decoding folds aliases together, e.g. both 27 and 170 decode as [`CharacterSet::ASCII`],
+    /// so a decoder that needs the original designator can check it against these.
+    /// ECI 29 (GB2312) is decoded as its superset GB18030 and so is listed for both.
+    pub fn values(&self) -> &'static [u32] {
+        match self {
+            CharacterSet::Cp437 => &[0, 2],
+            CharacterSet::ISO8859_1 => &[1, 3],
+            CharacterSet::ISO8859_2 => &[4],
+            CharacterSet::ISO8859_3 => &[5],
+            CharacterSet::ISO8859_4 => &[6],
+            CharacterSet::ISO8859_5 => &[7],
+            CharacterSet::ISO8859_6 => &[8],
+            CharacterSet::ISO8859_7 => &[9],
+            CharacterSet::ISO8859_8 => &[10],
+            CharacterSet::ISO8859_9 => &[11],
+            CharacterSet::ISO8859_10 => &[12],
+            CharacterSet::ISO8859_11 => &[13],
+            CharacterSet::ISO8859_13 => &[15],
+            CharacterSet::ISO8859_14 => &[16],
+            CharacterSet::ISO8859_15 => &[17],
+            CharacterSet::ISO8859_16 => &[18],
+            CharacterSet::Shift_JIS => &[20],
+            CharacterSet::Cp1250 => &[21],
+            CharacterSet::Cp1251 => &[22],
+            CharacterSet::Cp1252 => &[23],
+            CharacterSet::Cp1256 => &[24],
+            CharacterSet::UTF16BE => &[25],
+            CharacterSet::UTF8 => &[26],
+            CharacterSet::ASCII => &[27, 170],
+            CharacterSet::Big5 => &[28],
+            CharacterSet::GB2312 => &[29],
+            CharacterSet::GB18030 => &[29, 32],
+            CharacterSet::EUC_KR => &[30],
+            CharacterSet::UTF16LE => &[33],
+            CharacterSet::UTF32BE => &[34],
+            CharacterSet::UTF32LE => &[35],
+            CharacterSet::Binary => &[899],
+            CharacterSet::Unknown => &[],
+        }
+    }
+
+    /// Every character set with an ECI, for presenting the choices to a user.
+    ///
+    /// [`CharacterSet::get_charset_name`] gives each one's name and [`CharacterSet::values`]
+    /// its ECI values. [`CharacterSet::Unknown`] is left out.
+    pub fn all() -> &'static [CharacterSet] {
+        &[
+            CharacterSet::Cp437,
+            CharacterSet::ISO8859_1,
+            CharacterSet::ISO8859_2,
+            CharacterSet::ISO8859_3,
+            CharacterSet::ISO8859_4,
+            CharacterSet::ISO8859_5,
+            CharacterSet::ISO8859_6,
+            CharacterSet::ISO8859_7,
+            CharacterSet::ISO8859_8,
+            CharacterSet::ISO8859_9,
+            CharacterSet::ISO8859_10,
+            CharacterSet::ISO8859_11,
+            CharacterSet::ISO8859_13,
+            CharacterSet::ISO8859_14,
+            CharacterSet::ISO8859_15,
+            CharacterSet::ISO8859_16,
+            CharacterSet::Shift_JIS,
+            CharacterSet::Cp1250,
+            CharacterSet::Cp1251,
+            CharacterSet::Cp1252,
+            CharacterSet::Cp1256,
+            CharacterSet::UTF16BE,
+            CharacterSet::UTF8,
+            CharacterSet::ASCII,
+            CharacterSet::Big5,
+            CharacterSet::GB2312,
+            CharacterSet::GB18030,
+            CharacterSet::EUC_KR,
+            CharacterSet::UTF16LE,
+            CharacterSet::UTF32BE,
+            CharacterSet::UTF32LE,
+            CharacterSet::Binary,
+        ]
+    }
+
     // /**
     //  * @param charset Java character set object
     //  * @return CharacterSetECI representing ECI for character encoding, or null if it is legal
@@ -294,6 +394,7 @@ impl CharacterSet {
             "windows-1252" => Some(CharacterSet::Cp1252),
             "windows-1256" => Some(CharacterSet::Cp1256),
             "utf-16be" => Some(CharacterSet::UTF16BE),
+            "utf-16le" => Some(CharacterSet::UTF16LE),
             "utf-8" | "utf8" => Some(CharacterSet::UTF8),
             "us-ascii" => Some(CharacterSet::ASCII),
             "big5" => Some(CharacterSet::Big5),
@@ -318,14 +419,14 @@ impl CharacterSet {
                 .map(|data| data.to_vec())
                 .map_err(|e| Exceptions::format_with(format!("{e:?}")))
         } else {
-            self.get_base_encoder()
+            self.get_base_encoder()?
                 .encode(input, encoding::EncoderTrap::Strict)
                 .map_err(|e| Exceptions::format_with(e.to_string()))
         }
     }
 
     pub fn encode_replace(&self, input: &str) -> Result<Vec<u8>> {
-        self.get_base_encoder()
+        self.get_base_encoder()?
             .encode(input, encoding::EncoderTrap::Replace)
             .map_err(|e| Exceptions::format_with(e.to_string()))
     }
@@ -337,14 +438,14 @@ impl CharacterSet {
 
             Ok(String::borrow_from_cp437(&input, &CP437_CONTROL))
         } else {
-            self.get_base_encoder()
+            self.get_base_decoder()?
                 .decode(input, encoding::DecoderTrap::Strict)
                 .map_err(|e| Exceptions::format_with(e.to_string()))
         }
     }
 
     pub fn decode_replace(&self, input: &[u8]) -> Result<String> {
-        self.get_base_encoder()
+        self.get_base_decoder()?
             .decode(input, encoding::DecoderTrap::Replace)
             .map_err(|e| Exceptions::format_with(e.to_string()))
     }
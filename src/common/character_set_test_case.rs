@@ -0,0 +1,145 @@
+use super::{CharacterSet, Eci};
+use crate::Exceptions;
+
+#[test]
+fn test_unavailable_charset_is_an_error() {
+    // UTF-32 is a legal ECI but the encoding crate has no implementation for it
+    assert!(matches!(
+        CharacterSet::UTF32BE.decode(&[0, 0, 0, 0x41]),
+        Err(Exceptions::UnsupportedOperationException(_))
+    ));
+    assert!(matches!(
+        CharacterSet::UTF32LE.encode("A"),
+        Err(Exceptions::UnsupportedOperationException(_))
+    ));
+    assert!(CharacterSet::Unknown.decode_replace(&[0x41]).is_err());
+    assert!(CharacterSet::Binary.encode_replace("A").is_err());
+}
+
+#[test]
+fn test_available_charset() {
+    assert_eq!("Aé", CharacterSet::ISO8859_1.decode(&[0x41, 0xE9]).unwrap());
+    assert_eq!(
+        vec![0x41, 0xE9],
+        CharacterSet::ISO8859_1.encode("Aé").unwrap()
+    );
+}
+
+#[test]
+fn test_eci_value_round_trip() {
+    // 29 (GB2312) is decoded with its superset GB18030, like ZXing does
+    for value in (2..=13)
+        .chain(15..=18)
+        .chain(20..=28)
+        .chain([30])
+        .chain(32..=35)
+        .chain([170, 899])
+    {
+        assert_eq!(value, Eci::from(value) as i32, "ECI {value}");
+    }
+    assert_eq!(Eci::UTF16LE, Eci::from(33));
+    assert_eq!(Eci::Binary, Eci::from(899));
+    assert_eq!(Eci::GB18030, Eci::from(29));
+    assert_eq!(Eci::Unknown, Eci::from(14));
+}
+
+#[test]
+fn test_utf16le() {
+    assert_eq!(
+        Some(CharacterSet::UTF16LE),
+        CharacterSet::get_character_set_by_name(CharacterSet::UTF16LE.get_charset_name())
+    );
+    assert_eq!(CharacterSet::UTF16LE, CharacterSet::from(Eci::from(33)));
+    assert_eq!(
+        vec![0x41, 0, 0xE9, 0],
+        CharacterSet::UTF16LE.encode("Aé").unwrap()
+    );
+    assert_eq!(
+        "Aé",
+        CharacterSet::UTF16LE.decode(&[0x41, 0, 0xE9, 0]).unwrap()
+    );
+}
+
+#[test]
+fn test_gb18030_only_character() {
+    // U+20000 only exists in GB18030, as a four byte sequence
+    let bytes = CharacterSet::GB18030.encode("中𠀀").unwrap();
+    assert_eq!(vec![0xD6, 0xD0, 0x95, 0x32, 0x82, 0x36], bytes);
+    assert!(CharacterSet::GB2312.encode("𠀀").is_err());
+
+    for charset in [
+        CharacterSet::from(Eci::from(29)),
+        CharacterSet::from(Eci::GB2312),
+        CharacterSet::GB2312,
+    ] {
+        assert_eq!("中𠀀", charset.decode(&bytes).unwrap(), "{charset:?}");
+    }
+    assert_eq!(Eci::GB2312, Eci::from(CharacterSet::GB2312));
+
+    assert_eq!(
+        Some(CharacterSet::GB2312),
+        CharacterSet::get_character_set_by_name("GB2312")
+    );
+    assert_eq!(
+        Some(CharacterSet::GB18030),
+        CharacterSet::get_character_set_by_name("GB18030")
+    );
+}
+
+#[test]
+fn test_values() {
+    assert_eq!(&[27, 170], CharacterSet::ASCII.values());
+    assert_eq!(&[0, 2], CharacterSet::Cp437.values());
+    assert_eq!(&[1, 3], CharacterSet::ISO8859_1.values());
+    assert!(CharacterSet::Unknown.values().is_empty());
+
+    for charset in [
+        CharacterSet::ASCII,
+        CharacterSet::Cp437,
+        CharacterSet::ISO8859_1,
+        CharacterSet::UTF8,
+        CharacterSet::GB2312,
+        CharacterSet::GB18030,
+        CharacterSet::UTF16LE,
+        CharacterSet::Binary,
+    ] {
+        let values = charset.values();
+        assert!(values.windows(2).all(|pair| pair[0] < pair[1]));
+        // the value written when encoding is among them
+        assert!(values.contains(&(Eci::from(charset) as u32)), "{charset:?}");
+        for value in values {
+            assert_ne!(Eci::Unknown, Eci::from(*value), "{value}");
+        }
+    }
+    // the alias decodes to the same character set
+    assert_eq!(CharacterSet::ASCII, CharacterSet::from(Eci::from(170)));
+}
+
+#[test]
+fn test_all() {
+    let charsets = CharacterSet::all();
+    assert!(!charsets.is_empty());
+    assert!(!charsets.contains(&CharacterSet::Unknown));
+    assert!(charsets.contains(&CharacterSet::UTF8));
+    assert!(charsets.contains(&CharacterSet::ISO8859_1));
+    assert_eq!("utf-8", CharacterSet::UTF8.get_charset_name());
+    assert_eq!(&[26], CharacterSet::UTF8.values());
+    assert_eq!("iso-8859-1", CharacterSet::ISO8859_1.get_charset_name());
+    assert!(CharacterSet::ISO8859_1.values().contains(&3));
+    for charset in charsets {
+        assert!(!charset.values().is_empty(), "{charset:?}");
+    }
+
+    let ecis = Eci::all();
+    assert!(ecis
+        .windows(2)
+        .all(|pair| (pair[0] as i32) < (pair[1] as i32)));
+    assert!(!ecis.contains(&Eci::Unknown));
+    assert!(ecis.contains(&Eci::UTF8));
+    assert!(ecis.contains(&Eci::ISO8859_1));
+    assert_eq!(26, Eci::UTF8 as i32);
+    assert_eq!(3, Eci::ISO8859_1 as i32);
+    for eci in ecis {
+        assert_ne!(CharacterSet::Unknown, CharacterSet::from(*eci), "{eci}");
+    }
+}
@@ -18,7 +18,7 @@
 
 // import java.util.List;
 
-use std::{any::Any, rc::Rc};
+use std::{any::Any, fmt, rc::Rc};
 
 /**
  * <p>Encapsulates the result of decoding a matrix of bits. This typically
@@ -243,3 +243,38 @@ impl DecoderRXingResult {
         self.isMirrored = is_mirrored
     }
 }
+
+/// Compares everything except `other`, which cannot be compared.
+impl PartialEq for DecoderRXingResult {
+    fn eq(&self, other: &Self) -> bool {
+        self.rawBytes == other.rawBytes
+            && self.numBits == other.numBits
+            && self.text == other.text
+            && self.byteSegments == other.byteSegments
+            && self.ecLevel == other.ecLevel
+            && self.errorsCorrected == other.errorsCorrected
+            && self.erasures == other.erasures
+            && self.structuredAppendParity == other.structuredAppendParity
+            && self.structuredAppendSequenceNumber == other.structuredAppendSequenceNumber
+            && self.symbologyModifier == other.symbologyModifier
+            && self.contentType == other.contentType
+            && self.isMirrored == other.isMirrored
+    }
+}
+
+impl fmt::Debug for DecoderRXingResult {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("DecoderRXingResult")
+            .field("text", &self.text)
+            .field("ecLevel", &self.ecLevel)
+            .field("numBits", &self.numBits)
+            .field("errorsCorrected", &self.errorsCorrected)
+            .field("erasures", &self.erasures)
+            .field(
+                "structuredAppendSequenceNumber",
+                &self.structuredAppendSequenceNumber,
+            )
+            .field("structuredAppendParity", &self.structuredAppendParity)
+            .finish_non_exhaustive()
+    }
+}
@@ -0,0 +1,105 @@
+use std::rc::Rc;
+
+use crate::{point, BarcodeFormat, RXingResult, RXingResultMetadataType, RXingResultMetadataValue};
+
+use super::DecoderRXingResult;
+
+fn sample() -> DecoderRXingResult {
+    let mut result = DecoderRXingResult::with_sa(
+        vec![0x41, 0x42],
+        "AB".to_owned(),
+        vec![vec![0x41, 0x42]],
+        "M".to_owned(),
+        1,
+        7,
+    );
+    result.setErrorsCorrected(2);
+    result
+}
+
+#[test]
+fn test_equality() {
+    let mut left = sample();
+    let right = sample();
+    // other is not part of the comparison
+    left.setOther(Some(Rc::new(42)));
+    assert_eq!(left, right);
+
+    let mut different = sample();
+    different.setErasures(1);
+    assert_ne!(different, right);
+}
+
+#[test]
+fn test_debug() {
+    assert_eq!(
+        "DecoderRXingResult { text: \"AB\", ecLevel: \"M\", numBits: 2, errorsCorrected: 2, erasures: 0, structuredAppendSequenceNumber: 1, structuredAppendParity: 7, .. }",
+        format!("{:?}", sample())
+    );
+}
+
+#[test]
+fn test_into_rxing_result() {
+    let mut decoded = DecoderRXingResult::with_all(
+        vec![0x41, 0x42],
+        "AB".to_owned(),
+        vec![vec![0x41, 0x42]],
+        "M".to_owned(),
+        0x12,
+        7,
+        1,
+        "GS1".to_owned(),
+        true,
+    );
+    decoded.setErrorsCorrected(2);
+    let points = vec![point(1.0, 2.0), point(3.0, 4.0)];
+
+    let result = RXingResult::from_decoder_result(decoded, BarcodeFormat::QR_CODE, points.clone());
+    assert_eq!("AB", result.getText());
+    assert_eq!(&vec![0x41, 0x42], result.getRawBytes());
+    assert_eq!(&points, result.getPoints());
+    assert_eq!(&BarcodeFormat::QR_CODE, result.getBarcodeFormat());
+
+    let metadata = result.getRXingResultMetadata();
+    assert_eq!(7, metadata.len());
+    assert_eq!(
+        Some(&RXingResultMetadataValue::ByteSegments(vec![vec![
+            0x41, 0x42
+        ]])),
+        metadata.get(&RXingResultMetadataType::BYTE_SEGMENTS)
+    );
+    assert_eq!(
+        Some(&RXingResultMetadataValue::ErrorCorrectionLevel(
+            "M".to_owned()
+        )),
+        metadata.get(&RXingResultMetadataType::ERROR_CORRECTION_LEVEL)
+    );
+    assert_eq!(
+        Some(&RXingResultMetadataValue::StructuredAppendSequence(0x12)),
+        metadata.get(&RXingResultMetadataType::STRUCTURED_APPEND_SEQUENCE)
+    );
+    assert_eq!(
+        Some(&RXingResultMetadataValue::StructuredAppendParity(7)),
+        metadata.get(&RXingResultMetadataType::STRUCTURED_APPEND_PARITY)
+    );
+    assert_eq!(
+        Some(&RXingResultMetadataValue::ContentType("GS1".to_owned())),
+        metadata.get(&RXingResultMetadataType::CONTENT_TYPE)
+    );
+    assert_eq!(
+        Some(&RXingResultMetadataValue::IsMirrored(true)),
+        metadata.get(&RXingResultMetadataType::IS_MIRRORED)
+    );
+    assert_eq!(
+        Some(&RXingResultMetadataValue::SymbologyIdentifier(
+            "]Q1".to_owned()
+        )),
+        metadata.get(&RXingResultMetadataType::SYMBOLOGY_IDENTIFIER)
+    );
+
+    // nothing beyond text and bytes for a bare 1D result
+    let plain = DecoderRXingResult::new(vec![], "1234".to_owned(), vec![], String::new());
+    let result = RXingResult::from_decoder_result(plain, BarcodeFormat::CODE_39, Vec::new());
+    assert_eq!("1234", result.getText());
+    assert!(result.getRXingResultMetadata().is_empty());
+}
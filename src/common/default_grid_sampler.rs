@@ -19,44 +19,75 @@
 // import com.google.zxing.NotFoundException;
 
 use crate::common::Result;
-use crate::{Exceptions, Point};
+use crate::{Exceptions, NotFoundReason, Point};
 
-use super::{BitMatrix, GridSampler, PerspectiveTransform, Quadrilateral, SamplerControl};
+use super::{
+    BitMatrix, GridSampler, PerspectiveTransform, PerspectiveTransformF64, Quadrilateral,
+    SamplerControl,
+};
 
 /**
  * @author Sean Owen
  */
 #[derive(Default)]
-pub struct DefaultGridSampler;
+pub struct DefaultGridSampler {
+    high_precision: bool,
+    averaged: bool,
+}
 
-impl GridSampler for DefaultGridSampler {
-    fn sample_grid_detailed(
+impl DefaultGridSampler {
+    /// Creates a sampler which, if `high_precision` is set, builds and applies the transforms of
+    /// [`GridSampler::sample_grid_detailed`] in `f64` using [`PerspectiveTransformF64`].
+    ///
+    /// This matters for very large images. Transforms passed to [`GridSampler::sample_grid`]
+    /// through a [`SamplerControl`] are always `f32`.
+    pub fn new(high_precision: bool) -> Self {
+        Self::with_averaging(high_precision, false)
+    }
+
+    /// Creates a sampler as [`DefaultGridSampler::new`] does which, if `averaged` is set, reads
+    /// each module as [`DefaultGridSampler::sample_grid_averaged`] does.
+    pub fn with_averaging(high_precision: bool, averaged: bool) -> Self {
+        Self {
+            high_precision,
+            averaged,
+        }
+    }
+
+    /**
+     * Samples like [`GridSampler::sample_grid`], but reads each module by majority vote over the
+     * 3x3 pixels around its transformed center rather than from that single pixel.
+     *
+     * This is more robust on low resolution or noisy captures, where a module's center pixel may
+     * have been binarized to the wrong color. Pixels of the kernel falling outside the image are
+     * not counted.
+     */
+    pub fn sample_grid_averaged(
         &self,
         image: &BitMatrix,
         dimensionX: u32,
         dimensionY: u32,
-        dst: Quadrilateral,
-        src: Quadrilateral,
+        controls: &[SamplerControl],
     ) -> Result<BitMatrix> {
-        let transform = PerspectiveTransform::quadrilateralToQuadrilateral(dst, src)?;
-
-        self.sample_grid(
-            image,
-            dimensionX,
-            dimensionY,
-            &[SamplerControl::new(dimensionX, dimensionY, transform)],
-        )
+        let control = controls.first().ok_or(Exceptions::NOT_FOUND)?;
+        self.sample_grid_with(image, dimensionX, dimensionY, true, |points| {
+            control.transform.transform_points_single(points)
+        })
     }
 
-    fn sample_grid(
+    fn sample_grid_with(
         &self,
         image: &BitMatrix,
         dimensionX: u32,
         dimensionY: u32,
-        controls: &[SamplerControl],
+        averaged: bool,
+        transform_points: impl Fn(&mut [Point]),
     ) -> Result<BitMatrix> {
         if dimensionX == 0 || dimensionY == 0 {
-            return Err(Exceptions::NOT_FOUND);
+            return Err(Exceptions::not_found_because(
+                NotFoundReason::DimensionMismatch,
+                format!("cannot sample a {dimensionX}x{dimensionY} grid"),
+            ));
         }
         let mut bits = BitMatrix::new(dimensionX, dimensionY)?;
         let mut points = vec![Point::default(); dimensionX as usize];
@@ -71,11 +102,7 @@ impl GridSampler for DefaultGridSampler {
                 points[x].y = i_value;
                 x += 1;
             }
-            controls
-                .first()
-                .unwrap()
-                .transform
-                .transform_points_single(&mut points);
+            transform_points(&mut points);
             // Quick check to see if points transformed to something inside the image;
             // sufficient to check the endpoints
             self.checkAndNudgePoints(image, &mut points)?;
@@ -89,12 +116,17 @@ impl GridSampler for DefaultGridSampler {
                 //         "index out of bounds, see documentation in file for explanation".to_owned(),
                 //     ));
                 // }
-                if image
-                    .try_get(points[x].x as u32, points[x].y as u32)
-                    .ok_or(Exceptions::not_found_with(
+                let (image_x, image_y) =
+                    Self::pixel_at(image, points[x]).ok_or(Exceptions::not_found_because(
+                        NotFoundReason::SamplingOutOfBounds,
                         "index out of bounds, see documentation in file for explanation",
-                    ))?
-                {
+                    ))?;
+                let black = if averaged {
+                    Self::majority_black(image, image_x, image_y)
+                } else {
+                    image.get(image_x, image_y)
+                };
+                if black {
                     // Black(-ish) pixel
                     bits.set(x as u32, y);
                 }
@@ -113,4 +145,85 @@ impl GridSampler for DefaultGridSampler {
         }
         Ok(bits)
     }
+
+    /// The pixel `point` lies on, truncating towards zero like the `(int)` casts of the original,
+    /// or `None` if that is outside `image`.
+    ///
+    /// Even after nudging the endpoints, a twisted transform can map points between them far
+    /// off the image, including to negative or non-finite coordinates, which a plain `as u32`
+    /// would silently saturate into the image.
+    fn pixel_at(image: &BitMatrix, point: Point) -> Option<(u32, u32)> {
+        if !point.x.is_finite() || !point.y.is_finite() {
+            return None;
+        }
+        let (x, y) = (point.x as i64, point.y as i64);
+        if x < 0 || y < 0 || x >= image.getWidth() as i64 || y >= image.getHeight() as i64 {
+            return None;
+        }
+        Some((x as u32, y as u32))
+    }
+
+    /// Whether most of the in-bounds pixels of the 3x3 kernel centered on (x, y) are black.
+    fn majority_black(image: &BitMatrix, x: u32, y: u32) -> bool {
+        let mut black = 0;
+        let mut total = 0;
+        for kernel_y in y.saturating_sub(1)..=(y + 1).min(image.getHeight() - 1) {
+            for kernel_x in x.saturating_sub(1)..=(x + 1).min(image.getWidth() - 1) {
+                total += 1;
+                if image.get(kernel_x, kernel_y) {
+                    black += 1;
+                }
+            }
+        }
+        black * 2 > total
+    }
+}
+
+impl GridSampler for DefaultGridSampler {
+    fn sample_grid_detailed(
+        &self,
+        image: &BitMatrix,
+        dimensionX: u32,
+        dimensionY: u32,
+        dst: Quadrilateral,
+        src: Quadrilateral,
+    ) -> Result<BitMatrix> {
+        if self.high_precision {
+            let transform = PerspectiveTransformF64::quadrilateralToQuadrilateral(dst, src)?;
+            return self.sample_grid_with(image, dimensionX, dimensionY, self.averaged, |points| {
+                for point in points.iter_mut() {
+                    let (x, y) = transform.transform_point(point.x as f64, point.y as f64);
+                    // Only the pixel is needed. Rounding to f32 first could move a point lying
+                    // just before a pixel boundary onto it.
+                    point.x = x.floor() as f32;
+                    point.y = y.floor() as f32;
+                }
+            });
+        }
+
+        let transform = PerspectiveTransform::quadrilateralToQuadrilateral(dst, src)?;
+
+        self.sample_grid(
+            image,
+            dimensionX,
+            dimensionY,
+            &[SamplerControl::new(dimensionX, dimensionY, transform)],
+        )
+    }
+
+    fn sample_grid(
+        &self,
+        image: &BitMatrix,
+        dimensionX: u32,
+        dimensionY: u32,
+        controls: &[SamplerControl],
+    ) -> Result<BitMatrix> {
+        if self.averaged {
+            return self.sample_grid_averaged(image, dimensionX, dimensionY, controls);
+        }
+        let control = controls.first().ok_or(Exceptions::NOT_FOUND)?;
+        self.sample_grid_with(image, dimensionX, dimensionY, false, |points| {
+            control.transform.transform_points_single(points)
+        })
+    }
 }
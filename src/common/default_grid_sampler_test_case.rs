@@ -0,0 +1,167 @@
+use crate::{point, Exceptions, NotFoundReason};
+
+use super::{BitMatrix, DefaultGridSampler, GridSampler, Quadrilateral};
+
+/// Solves for the homography mapping the corners of `from` onto those of `to` directly, by
+/// Gaussian elimination in `f64`, as a reference independent of the transforms under test.
+fn reference_homography(from: [(f64, f64); 4], to: [(f64, f64); 4]) -> [f64; 8] {
+    let mut rows = [[0.0; 9]; 8];
+    for (i, ((u, v), (x, y))) in from.into_iter().zip(to).enumerate() {
+        rows[2 * i] = [u, v, 1.0, 0.0, 0.0, 0.0, -u * x, -v * x, x];
+        rows[2 * i + 1] = [0.0, 0.0, 0.0, u, v, 1.0, -u * y, -v * y, y];
+    }
+    for col in 0..8 {
+        let pivot = (col..8)
+            .max_by(|a, b| rows[*a][col].abs().total_cmp(&rows[*b][col].abs()))
+            .unwrap();
+        rows.swap(col, pivot);
+        let pivot_row = rows[col];
+        for (i, row) in rows.iter_mut().enumerate() {
+            if i != col {
+                let factor = row[col] / pivot_row[col];
+                for (value, pivot) in row.iter_mut().zip(pivot_row).skip(col) {
+                    *value -= factor * pivot;
+                }
+            }
+        }
+    }
+    std::array::from_fn(|i| rows[i][8] / rows[i][i])
+}
+
+#[test]
+fn test_high_precision_sampling_of_large_image() {
+    const SIZE: u32 = 2000;
+    let corners = [(3.0, 7.0), (1990.0, 30.0), (1700.0, 1900.0), (40.0, 1999.0)];
+    let grid = [
+        (0.0, 0.0),
+        (SIZE as f64, 0.0),
+        (SIZE as f64, SIZE as f64),
+        (0.0, SIZE as f64),
+    ];
+    let to_quad = |points: [(f64, f64); 4]| {
+        let [p0, p1, p2, p3] = points.map(|(x, y)| point(x as f32, y as f32));
+        Quadrilateral::new(p0, p1, p2, p3)
+    };
+
+    // one pixel per module, so any rounding error of at least a pixel picks the wrong module
+    let mut image = BitMatrix::with_single_dimension(SIZE).unwrap();
+    for y in 0..SIZE {
+        for x in (y & 1..SIZE).step_by(2) {
+            image.set(x, y);
+        }
+    }
+
+    let h = reference_homography(grid, corners);
+    let mut expected = BitMatrix::with_single_dimension(SIZE).unwrap();
+    for y in 0..SIZE {
+        for x in 0..SIZE {
+            let (u, v) = (x as f64 + 0.5, y as f64 + 0.5);
+            let w = h[6] * u + h[7] * v + 1.0;
+            let image_x = (h[0] * u + h[1] * v + h[2]) / w;
+            let image_y = (h[3] * u + h[4] * v + h[5]) / w;
+            if image.get(image_x as u32, image_y as u32) {
+                expected.set(x, y);
+            }
+        }
+    }
+
+    let sample = |sampler: DefaultGridSampler| {
+        sampler
+            .sample_grid_detailed(&image, SIZE, SIZE, to_quad(grid), to_quad(corners))
+            .unwrap()
+    };
+    let mut single_precision = sample(DefaultGridSampler::default());
+    let double_precision = sample(DefaultGridSampler::new(true));
+
+    assert_eq!(expected, double_precision);
+    single_precision.xor(&expected).unwrap();
+    assert!(single_precision.cardinality() > 0);
+}
+
+#[test]
+fn test_averaged_sampling_of_degraded_image() {
+    const DIMENSION: u32 = 7;
+    const MODULE_SIZE: u32 = 3;
+    let size = DIMENSION * MODULE_SIZE;
+
+    let mut expected = BitMatrix::with_single_dimension(DIMENSION).unwrap();
+    let mut image = BitMatrix::with_single_dimension(size).unwrap();
+    for y in 0..DIMENSION {
+        for x in 0..DIMENSION {
+            if (x * 3 + y * 5) % 7 < 3 {
+                expected.set(x, y);
+                image
+                    .setRegion(x * MODULE_SIZE, y * MODULE_SIZE, MODULE_SIZE, MODULE_SIZE)
+                    .unwrap();
+            }
+        }
+    }
+    // Flip the center pixel of every other module, where single pixel sampling reads from.
+    for y in 0..DIMENSION {
+        for x in (y & 1..DIMENSION).step_by(2) {
+            image.flip_coords(x * MODULE_SIZE + 1, y * MODULE_SIZE + 1);
+        }
+    }
+
+    let grid = Quadrilateral::new(
+        point(0.0, 0.0),
+        point(DIMENSION as f32, 0.0),
+        point(DIMENSION as f32, DIMENSION as f32),
+        point(0.0, DIMENSION as f32),
+    );
+    let corners = Quadrilateral::new(
+        point(0.0, 0.0),
+        point(size as f32, 0.0),
+        point(size as f32, size as f32),
+        point(0.0, size as f32),
+    );
+    let sample = |sampler: DefaultGridSampler| {
+        sampler
+            .sample_grid_detailed(&image, DIMENSION, DIMENSION, grid, corners)
+            .unwrap()
+    };
+
+    assert_ne!(expected, sample(DefaultGridSampler::default()));
+    assert_eq!(
+        expected,
+        sample(DefaultGridSampler::with_averaging(false, true))
+    );
+    assert_eq!(
+        expected,
+        sample(DefaultGridSampler::with_averaging(true, true))
+    );
+}
+
+#[test]
+fn test_points_mapped_off_image_between_endpoints() {
+    // Sampling 40 modules along the top edge extrapolates far past the right of this trapezoid,
+    // across the line the perspective sends to infinity, so the row of points starts and ends in
+    // the image but passes through it and out again: x runs from 30 up to 180, jumps to -140,
+    // then comes back up to 17.
+    let dst = Quadrilateral::new(
+        point(4.0, 0.0),
+        point(6.0, 0.0),
+        point(6.0, 2.0),
+        point(4.0, 2.0),
+    );
+    let src = Quadrilateral::new(
+        point(40.0, 40.0),
+        point(60.0, 30.0),
+        point(60.0, 70.0),
+        point(40.0, 60.0),
+    );
+    let image = BitMatrix::with_single_dimension(200).unwrap();
+
+    for sampler in [
+        DefaultGridSampler::new(false),
+        DefaultGridSampler::new(true),
+    ] {
+        assert!(matches!(
+            sampler.sample_grid_detailed(&image, 40, 1, dst, src),
+            Err(Exceptions::NotFoundException(
+                NotFoundReason::SamplingOutOfBounds,
+                _
+            ))
+        ));
+    }
+}
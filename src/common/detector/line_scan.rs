@@ -0,0 +1,69 @@
+use crate::{common::BitMatrix, point, Point};
+
+/// Traces a line from `from`, which should be in a black region, towards `to` until it has
+/// crossed black, then white, then black and reached white again, and returns the distance
+/// from `from` to that point.
+///
+/// This is ZXing's `sizeOfBlackWhiteBlackRun`, used to measure how wide a finder pattern is
+/// from its center when the pattern may be skewed or rotated. The line is walked with a mild
+/// variant of Bresenham's algorithm. If the line ends on the final black run, the pixel just past
+/// `to` is assumed to be white. Returns NaN if no black-white-black run is found. Both points
+/// must lie within `image`.
+pub fn black_white_black_run_centered(image: &BitMatrix, from: (i32, i32), to: (i32, i32)) -> f32 {
+    let ((mut from_x, mut from_y), (mut to_x, mut to_y)) = (from, to);
+    // see http://en.wikipedia.org/wiki/Bresenham's_line_algorithm
+    let steep = (to_y - from_y).abs() > (to_x - from_x).abs();
+    if steep {
+        std::mem::swap(&mut from_x, &mut from_y);
+        std::mem::swap(&mut to_x, &mut to_y);
+    }
+
+    let dx = (to_x - from_x).abs();
+    let dy = (to_y - from_y).abs();
+    let mut error = -dx / 2;
+    let x_step = if from_x < to_x { 1 } else { -1 };
+    let y_step = if from_y < to_y { 1 } else { -1 };
+
+    // In black pixels, looking for white, first or second time.
+    let mut state = 0;
+    // Loop up until x == to_x, but not beyond
+    let x_limit = to_x + x_step;
+
+    let mut x = from_x;
+    let mut y = from_y;
+    while x != x_limit {
+        let (real_x, real_y) = if steep { (y, x) } else { (x, y) };
+
+        // Scanning black in state 0,2 and white in state 1, so if we find the wrong
+        // color, advance to next state or end if we are in state 2 already
+        if (state == 1) == image.get(real_x as u32, real_y as u32) {
+            if state == 2 {
+                return Point::distance(
+                    point(x as f32, y as f32),
+                    point(from_x as f32, from_y as f32),
+                );
+            }
+            state += 1;
+        }
+
+        error += dy;
+        if error > 0 {
+            if y == to_y {
+                break;
+            }
+            y += y_step;
+            error -= dx;
+        }
+
+        x += x_step;
+    }
+    // Found black-white-black; give the benefit of the doubt that the next pixel outside the image
+    // is "white" so this last point at (to_x+x_step,to_y) is the right ending.
+    if state == 2 {
+        return Point::distance(
+            point((to_x + x_step) as f32, to_y as f32),
+            point(from_x as f32, from_y as f32),
+        );
+    }
+    f32::NAN
+}
@@ -0,0 +1,42 @@
+use crate::common::BitMatrix;
+
+use super::black_white_black_run_centered;
+
+/// A 7x7 module finder pattern with 3 pixel modules, its top-left corner at (3, 3) and its
+/// center at (13, 13).
+fn finder_pattern() -> BitMatrix {
+    let mut image = BitMatrix::with_single_dimension(30).unwrap();
+    image.setRegion(3, 3, 21, 21).unwrap();
+    for y in 6..21 {
+        for x in 6..21 {
+            image.unset(x, y);
+        }
+    }
+    image.setRegion(9, 9, 9, 9).unwrap();
+    image
+}
+
+#[test]
+fn test_black_white_black_run_centered() {
+    let image = finder_pattern();
+
+    // Center run to 17, white ring 18-20, outer ring 21-23, then white at 24.
+    assert_eq!(
+        11.0,
+        black_white_black_run_centered(&image, (13, 13), (29, 13))
+    );
+    assert_eq!(
+        11.0,
+        black_white_black_run_centered(&image, (13, 13), (13, 0))
+    );
+    let diagonal = black_white_black_run_centered(&image, (13, 13), (29, 29));
+    assert!((diagonal - 11.0 * std::f32::consts::SQRT_2).abs() < 1e-4);
+
+    // The line ends on the outer ring, so the pixel past its end is taken to be white.
+    assert_eq!(
+        10.0,
+        black_white_black_run_centered(&image, (13, 13), (22, 13))
+    );
+
+    assert!(black_white_black_run_centered(&image, (13, 13), (19, 13)).is_nan());
+}
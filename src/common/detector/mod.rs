@@ -3,3 +3,9 @@ pub use monochrome_rectangle_detector::*;
 
 mod white_rectangle_detector;
 pub use white_rectangle_detector::*;
+
+mod line_scan;
+pub use line_scan::*;
+
+#[cfg(test)]
+mod line_scan_test_case;
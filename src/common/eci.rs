@@ -44,6 +44,47 @@ impl Eci {
     pub fn can_encode(self) -> bool {
         (self as i32) >= 899
     }
+
+    /// Every known ECI in ascending order, for presenting the choices to a user; `self as i32`
+    /// is the ECI number and the name is that of its [`CharacterSet`]. [`Eci::Unknown`] is left
+    /// out.
+    pub fn all() -> &'static [Eci] {
+        &[
+            Eci::Cp437,
+            Eci::ISO8859_1,
+            Eci::ISO8859_2,
+            Eci::ISO8859_3,
+            Eci::ISO8859_4,
+            Eci::ISO8859_5,
+            Eci::ISO8859_6,
+            Eci::ISO8859_7,
+            Eci::ISO8859_8,
+            Eci::ISO8859_9,
+            Eci::ISO8859_10,
+            Eci::ISO8859_11,
+            Eci::ISO8859_13,
+            Eci::ISO8859_14,
+            Eci::ISO8859_15,
+            Eci::ISO8859_16,
+            Eci::Shift_JIS,
+            Eci::Cp1250,
+            Eci::Cp1251,
+            Eci::Cp1252,
+            Eci::Cp1256,
+            Eci::UTF16BE,
+            Eci::UTF8,
+            Eci::ASCII,
+            Eci::Big5,
+            Eci::GB2312,
+            Eci::EUC_KR,
+            Eci::GB18030,
+            Eci::UTF16LE,
+            Eci::UTF32BE,
+            Eci::UTF32LE,
+            Eci::ISO646_Inv,
+            Eci::Binary,
+        ]
+    }
 }
 
 impl From<u32> for Eci {
@@ -86,8 +127,8 @@ impl From<i32> for Eci {
             33 => Eci::UTF16LE,
             34 => Eci::UTF32BE,
             35 => Eci::UTF32LE,
-            170 => Eci::ASCII,
-            898 => Eci::Binary,
+            170 => Eci::ISO646_Inv,
+            899 => Eci::Binary,
             _ => Eci::Unknown,
         }
     }
@@ -161,7 +202,8 @@ impl From<Eci> for CharacterSet {
             Eci::UTF8 => CharacterSet::UTF8,
             Eci::ASCII => CharacterSet::ASCII,
             Eci::Big5 => CharacterSet::Big5,
-            Eci::GB2312 => CharacterSet::GB2312,
+            // ECI 29 is decoded as GB18030, of which GB2312 is a subset
+            Eci::GB2312 => CharacterSet::GB18030,
             Eci::EUC_KR => CharacterSet::EUC_KR,
             Eci::GB18030 => CharacterSet::GB18030,
             Eci::UTF16LE => CharacterSet::UTF16LE,
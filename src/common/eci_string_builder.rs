@@ -21,9 +21,11 @@
 // import java.nio.charset.Charset;
 // import java.nio.charset.StandardCharsets;
 
-use std::fmt;
+use std::{fmt, ops::Range};
 
-use super::{CharacterSet, Eci};
+use crate::{common::Result, DecodingHintDictionary};
+
+use super::{CharacterSet, Eci, StringUtils};
 
 /**
  * Class that converts a sequence of ECIs and bytes into a string
@@ -35,7 +37,8 @@ pub struct ECIStringBuilder {
     is_eci: bool,
     eci_result: Option<String>,
     bytes: Vec<u8>,
-    eci_positions: Vec<(Eci, usize, usize)>, // (Eci, start, end)
+    // (Eci, start); each run ends where the next one starts, the last at the end of bytes
+    eci_positions: Vec<(Eci, usize)>,
 }
 
 impl ECIStringBuilder {
@@ -77,6 +80,62 @@ impl ECIStringBuilder {
         self.bytes.extend_from_slice(value)
     }
 
+    /**
+     * Appends {@code value} as byte values, guessing their character set with
+     * {@link StringUtils#guessCharset} and appending an ECI wherever the guess changes.
+     *
+     * The guess is made per whitespace separated word, so that mixed content such as Latin-1
+     * text followed by Shift_JIS text is split into runs that each decode correctly. ASCII only
+     * words are valid in all of these and stay with the run before them.
+     *
+     * @param value bytes to append
+     */
+    pub fn append_bytes_autodetect(&mut self, value: &[u8]) {
+        let hints = DecodingHintDictionary::new();
+        let mut runs: Vec<(CharacterSet, usize)> = Vec::new();
+        let mut offset = 0;
+        for word in value.split_inclusive(u8::is_ascii_whitespace) {
+            if !word.is_ascii() {
+                let charset =
+                    StringUtils::guessCharset(word, &hints).unwrap_or(CharacterSet::ISO8859_1);
+                match runs.last() {
+                    Some((last, _)) if *last == charset => {}
+                    // leading ASCII words belong to the first run
+                    None => runs.push((charset, 0)),
+                    Some(_) => runs.push((charset, offset)),
+                }
+            }
+            offset += word.len();
+        }
+
+        if runs.is_empty() {
+            self.append_bytes(value);
+            return;
+        }
+        for (i, (charset, start)) in runs.iter().enumerate() {
+            let end = runs.get(i + 1).map_or(value.len(), |(_, next)| *next);
+            self.append_eci(Eci::from(*charset));
+            self.append_bytes(&value[*start..end]);
+        }
+    }
+
+    /// Appends the bytes of `other`, keeping the ECI each of them is decoded with.
+    ///
+    /// Bytes `other` holds before its first ECI are appended as [`Eci::ISO8859_1`], and once done
+    /// this builder continues in the last ECI of `other`.
+    pub fn append_builder(&mut self, other: &ECIStringBuilder) {
+        for (eci, range) in other.segments() {
+            self.append_eci(eci);
+            self.append_bytes(&other.bytes[range]);
+        }
+        // an ECI with no bytes after it still applies to whatever is appended next
+        if let Some((eci, start)) = other.eci_positions.last() {
+            if *start == other.bytes.len() {
+                self.append_eci(*eci);
+            }
+        }
+    }
+
     /**
      * Appends the characters in {@code value} as bytes values
      *
@@ -112,71 +171,28 @@ impl ECIStringBuilder {
         }
 
         if self.is_eci {
-            if let Some(last) = self.eci_positions.last_mut() {
-                last.2 = self.bytes.len()
-            }
-
-            self.eci_positions.push((eci, self.bytes.len(), 0));
+            self.eci_positions.push((eci, self.bytes.len()));
         }
     }
 
-    /// Finishes encoding anything in the buffer using the current ECI and resets.
+    /// Decodes all appended bytes, each run with the character set of its ECI.
     ///
-    /// This function can panic
-    pub fn encodeCurrentBytesIfAny(&self) -> String {
+    /// Fails if a run cannot be decoded with its character set.
+    pub fn encodeCurrentBytesIfAny(&self) -> Result<String> {
         let mut encoded_string = String::with_capacity(self.bytes.len());
-        // First encode the first set
-        let (_, end, _) =
-            *self
-                .eci_positions
-                .first()
-                .unwrap_or(&(Eci::ISO8859_1, self.bytes.len(), 0));
-
-        encoded_string.push_str(
-            &Self::encode_segment(&self.bytes[0..end], Eci::ISO8859_1).unwrap_or_default(),
-        );
-
-        // If there are more sets, encode each of them in turn
-        for (eci, eci_start, eci_end) in &self.eci_positions {
-            // let (_,end) = *self.eci_positions.first().unwrap_or(&(*eci, self.bytes.len()));
-            let end = if *eci_end == 0 {
-                self.bytes.len()
-            } else {
-                *eci_end
-            };
-            encoded_string.push_str(
-                &Self::encode_segment(&self.bytes[*eci_start..end], *eci).unwrap_or_default(),
-            );
+        for (eci, range) in self.segments() {
+            encoded_string.push_str(&Self::encode_segment(&self.bytes[range], eci)?);
         }
-
-        // Return the result
-        encoded_string
+        Ok(encoded_string)
     }
 
-    fn encode_segment(bytes: &[u8], eci: Eci) -> Option<String> {
-        let mut encoded_string = String::with_capacity(bytes.len());
-        if ![Eci::Binary, Eci::Unknown].contains(&eci) {
-            if eci == Eci::UTF8 {
-                if !bytes.is_empty() {
-                    encoded_string.push_str(&CharacterSet::UTF8.decode(bytes).ok()?);
-                } else {
-                    return None;
-                }
-            } else if !bytes.is_empty() {
-                encoded_string.push_str(&CharacterSet::from(eci).decode(bytes).ok()?);
-            } else {
-                return None;
-            }
-        } else {
-            for byte in bytes {
-                encoded_string.push(char::from(*byte))
-            }
-        }
-
-        if encoded_string.is_empty() {
-            None
+    fn encode_segment(bytes: &[u8], eci: Eci) -> Result<String> {
+        if [Eci::Binary, Eci::Unknown].contains(&eci) {
+            Ok(bytes.iter().map(|byte| char::from(*byte)).collect())
+        } else if bytes.is_empty() {
+            Ok(String::new())
         } else {
-            Some(encoded_string)
+            CharacterSet::from(eci).decode(bytes)
         }
     }
 
@@ -205,10 +221,99 @@ impl ECIStringBuilder {
         self.bytes.is_empty()
     }
 
-    pub fn build_result(mut self) -> Self {
-        self.eci_result = Some(self.encodeCurrentBytesIfAny());
+    /// Removes all appended bytes and ECIs so the builder can be reused.
+    ///
+    /// The allocated capacity is kept.
+    pub fn clear(&mut self) {
+        self.is_eci = false;
+        self.eci_result = None;
+        self.bytes.clear();
+        self.eci_positions.clear();
+    }
+
+    /// The byte range of each run of bytes decoded with a single ECI, in order.
+    ///
+    /// Bytes appended before the first ECI are reported as [`Eci::ISO8859_1`], which is how they
+    /// are decoded. Empty runs are skipped.
+    pub fn segments(&self) -> Vec<(Eci, Range<usize>)> {
+        let starts = std::iter::once((Eci::ISO8859_1, 0)).chain(self.eci_positions.iter().copied());
+        let ends = self
+            .eci_positions
+            .iter()
+            .map(|(_, start)| *start)
+            .chain(std::iter::once(self.bytes.len()));
+        starts
+            .zip(ends)
+            .map(|((eci, start), end)| (eci, start..end))
+            .filter(|(_, range)| !range.is_empty())
+            .collect()
+    }
+
+    /// The raw bytes of the run at `index` in [`ECIStringBuilder::segments`].
+    ///
+    /// Unlike the decoded text, this is lossless for [`Eci::Binary`] runs.
+    pub fn segment_bytes(&self, index: usize) -> Option<&[u8]> {
+        let (_, range) = self.segments().into_iter().nth(index)?;
+        Some(&self.bytes[range])
+    }
 
-        self
+    /// Like [`ECIStringBuilder::segments`], along with the text each run decodes to, or the error
+    /// decoding it with its character set failed with.
+    ///
+    /// Concatenating the texts gives the same string as [`ECIStringBuilder::try_to_string`].
+    pub fn decoded_segments(&self) -> Vec<(Eci, Range<usize>, Result<String>)> {
+        self.segments()
+            .into_iter()
+            .map(|(eci, range)| {
+                let text = Self::encode_segment(&self.bytes[range.clone()], eci);
+                (eci, range, text)
+            })
+            .collect()
+    }
+
+    /// Each decoded character along with the range of [`ECIStringBuilder::bytes`] it was decoded
+    /// from, in order.
+    ///
+    /// The characters are those of [`ECIStringBuilder::to_string`]; a run that cannot be decoded
+    /// contributes none.
+    pub fn char_byte_ranges(&self) -> Vec<(char, Range<usize>)> {
+        let mut chars = Vec::with_capacity(self.bytes.len());
+        for (eci, range) in self.segments() {
+            if Self::encode_segment(&self.bytes[range.clone()], eci).is_err() {
+                continue;
+            }
+            // grow each piece a byte at a time until it decodes, so that it covers exactly the
+            // bytes of one character of a multi-byte encoding
+            let mut start = range.start;
+            for end in range.start + 1..=range.end {
+                let Ok(text) = Self::encode_segment(&self.bytes[start..end], eci) else {
+                    continue;
+                };
+                if !text.is_empty() {
+                    chars.extend(text.chars().map(|c| (c, start..end)));
+                    start = end;
+                }
+            }
+        }
+        chars
+    }
+
+    /// Decodes the appended bytes once, so that later calls to `to_string` reuse the text.
+    ///
+    /// Fails if a run cannot be decoded with its character set.
+    pub fn build_result(mut self) -> Result<Self> {
+        self.eci_result = Some(self.encodeCurrentBytesIfAny()?);
+
+        Ok(self)
+    }
+
+    /// Like `to_string`, but fails rather than leave out a run that cannot be decoded with its
+    /// character set.
+    pub fn try_to_string(&self) -> Result<String> {
+        match &self.eci_result {
+            Some(res) => Ok(res.clone()),
+            None => self.encodeCurrentBytesIfAny(),
+        }
     }
 }
 
@@ -217,7 +322,13 @@ impl fmt::Display for ECIStringBuilder {
         if let Some(res) = &self.eci_result {
             write!(f, "{res}")
         } else {
-            write!(f, "{}", self.encodeCurrentBytesIfAny())
+            // runs that cannot be decoded are left out, see try_to_string
+            for (eci, range) in self.segments() {
+                if let Ok(text) = Self::encode_segment(&self.bytes[range], eci) {
+                    write!(f, "{text}")?;
+                }
+            }
+            Ok(())
         }
     }
 }
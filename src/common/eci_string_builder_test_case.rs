@@ -0,0 +1,294 @@
+use super::{CharacterSet, ECIStringBuilder, Eci};
+use crate::Exceptions;
+
+#[test]
+fn test_clear() {
+    let mut builder = ECIStringBuilder::with_capacity(32);
+    builder.append_eci(Eci::UTF8);
+    builder.append_bytes("héllo".as_bytes());
+    assert_eq!("héllo", builder.to_string());
+
+    builder.clear();
+    assert!(builder.is_empty());
+    assert_eq!("", builder.to_string());
+
+    // without the earlier UTF-8 ECI these bytes are read as ISO-8859-1
+    builder.append_bytes(&[0x41, 0xE9]);
+    assert_eq!("Aé", builder.to_string());
+
+    let result = builder.build_result().unwrap();
+    assert_eq!("Aé", result.to_string());
+}
+
+#[test]
+fn test_mutation_invalidates_cached_result() {
+    let mut builder = ECIStringBuilder::default();
+    builder.append_bytes(b"ab");
+    let mut builder = builder.build_result().unwrap();
+    assert_eq!("ab", builder.to_string());
+
+    builder.append_byte(b'c');
+    assert_eq!("abc", builder.to_string());
+    builder.append_char('d');
+    assert_eq!("abcd", builder.to_string());
+    builder.append_eci(Eci::UTF8);
+    builder.append_bytes("é".as_bytes());
+    assert_eq!("abcdé", builder.to_string());
+}
+
+#[test]
+fn test_clear_after_build_result() {
+    let mut builder = ECIStringBuilder::default();
+    builder.append_eci(Eci::UTF8);
+    builder.append_bytes("日本".as_bytes());
+    let mut builder = builder.build_result().unwrap();
+    assert_eq!("日本", builder.to_string());
+
+    builder.clear();
+    assert!(builder.is_empty());
+    assert_eq!("", builder.to_string());
+
+    // neither the cached result nor the UTF-8 segment may leak into the next decode
+    builder.append_bytes(&[0xC3, 0xA9]);
+    let builder = builder.build_result().unwrap();
+    assert_eq!("Ã©", builder.to_string());
+}
+
+#[test]
+fn test_unavailable_charset_is_an_error() {
+    let mut builder = ECIStringBuilder::default();
+    builder.append_bytes(b"ok");
+    builder.append_eci(Eci::UTF32BE);
+    builder.append_bytes(&[0, 0, 0, 0x41]);
+    assert!(matches!(
+        builder.encodeCurrentBytesIfAny(),
+        Err(Exceptions::UnsupportedOperationException(_))
+    ));
+    assert!(builder.try_to_string().is_err());
+    assert!(builder.build_result().is_err());
+}
+
+#[test]
+fn test_try_to_string() {
+    let mut builder = ECIStringBuilder::default();
+    builder.append_eci(Eci::UTF8);
+    builder.append_bytes("é".as_bytes());
+    assert_eq!("é", builder.try_to_string().unwrap());
+    assert_eq!(
+        "é",
+        builder.build_result().unwrap().try_to_string().unwrap()
+    );
+
+    // invalid UTF-8 is reported rather than dropped
+    let mut builder = ECIStringBuilder::default();
+    builder.append_eci(Eci::UTF8);
+    builder.append_bytes(&[0xC3, 0x28]);
+    assert!(matches!(
+        builder.try_to_string(),
+        Err(Exceptions::FormatException(_))
+    ));
+}
+
+#[test]
+fn test_append_bytes_autodetect() {
+    let japanese = CharacterSet::Shift_JIS.encode("日本語").unwrap();
+    let mut bytes = b"Gr\xFC\xDFe aus ".to_vec();
+    bytes.extend_from_slice(&japanese);
+
+    let mut builder = ECIStringBuilder::default();
+    builder.append_bytes_autodetect(&bytes);
+    assert_eq!(
+        vec![(Eci::ISO8859_1, 0..10), (Eci::Shift_JIS, 10..16)],
+        builder.segments()
+    );
+    assert_eq!("Grüße aus 日本語", builder.to_string());
+}
+
+#[test]
+fn test_append_bytes_autodetect_ascii() {
+    let mut builder = ECIStringBuilder::default();
+    builder.append_bytes_autodetect(b"plain text");
+    assert_eq!(vec![(Eci::ISO8859_1, 0..10)], builder.segments());
+    assert_eq!("plain text", builder.to_string());
+}
+
+#[test]
+fn test_append_builder() {
+    let mut utf8 = ECIStringBuilder::default();
+    utf8.append_eci(Eci::UTF8);
+    utf8.append_bytes("Grüße ".as_bytes());
+    let mut latin1 = ECIStringBuilder::default();
+    latin1.append_bytes(b"caf\xE9");
+
+    let mut builder = ECIStringBuilder::default();
+    builder.append_bytes(b"> ");
+    builder.append_builder(&utf8);
+    builder.append_builder(&latin1);
+    assert_eq!(
+        vec![
+            (Eci::ISO8859_1, 0..2),
+            (Eci::UTF8, 2..10),
+            (Eci::ISO8859_1, 10..14)
+        ],
+        builder.segments()
+    );
+    assert_eq!("> Grüße café", builder.to_string());
+
+    // a trailing ECI carries over to later appends
+    let mut trailing = ECIStringBuilder::default();
+    trailing.append_eci(Eci::UTF8);
+    let mut builder = ECIStringBuilder::default();
+    builder.append_builder(&latin1);
+    builder.append_builder(&trailing);
+    builder.append_bytes("é".as_bytes());
+    assert_eq!("caféé", builder.to_string());
+}
+
+#[test]
+fn test_decoded_segments() {
+    let mut builder = ECIStringBuilder::default();
+    builder.append_bytes(b"(01)");
+    builder.append_eci(Eci::UTF8);
+    builder.append_bytes("Grüße".as_bytes());
+    builder.append_eci(Eci::Shift_JIS);
+    builder.append_bytes(&CharacterSet::Shift_JIS.encode("日本").unwrap());
+
+    assert_eq!(
+        vec![
+            (Eci::ISO8859_1, 0..4, Ok("(01)".to_owned())),
+            (Eci::UTF8, 4..11, Ok("Grüße".to_owned())),
+            (Eci::Shift_JIS, 11..15, Ok("日本".to_owned())),
+        ],
+        builder.decoded_segments()
+    );
+    assert_eq!(
+        builder.try_to_string(),
+        builder
+            .decoded_segments()
+            .into_iter()
+            .map(|(_, _, text)| text)
+            .collect::<Result<String, _>>()
+    );
+}
+
+#[test]
+fn test_decoded_segments_undecodable() {
+    let mut builder = ECIStringBuilder::default();
+    builder.append_bytes(b"ok");
+    builder.append_eci(Eci::UTF8);
+    builder.append_bytes(&[0xC3, 0x28]);
+    builder.append_eci(Eci::UTF32BE);
+    builder.append_bytes(&[0, 0, 0, 0x41]);
+
+    let segments = builder.decoded_segments();
+    assert_eq!(3, segments.len());
+    assert_eq!((Eci::ISO8859_1, 0..2, Ok("ok".to_owned())), segments[0]);
+    assert!(matches!(
+        segments[1],
+        (Eci::UTF8, _, Err(Exceptions::FormatException(_)))
+    ));
+    assert!(matches!(
+        segments[2],
+        (
+            Eci::UTF32BE,
+            _,
+            Err(Exceptions::UnsupportedOperationException(_))
+        )
+    ));
+}
+
+#[test]
+fn test_eci_at_start() {
+    let mut builder = ECIStringBuilder::default();
+    builder.append_eci(Eci::UTF8);
+    builder.append_bytes("Grüße".as_bytes());
+    builder.append_eci(Eci::ISO8859_1);
+    builder.append_bytes(b"caf\xE9");
+    assert_eq!(
+        vec![(Eci::UTF8, 0..7), (Eci::ISO8859_1, 7..11)],
+        builder.segments()
+    );
+    assert_eq!("Grüßecafé", builder.to_string());
+}
+
+#[test]
+fn test_consecutive_ecis() {
+    let mut builder = ECIStringBuilder::default();
+    builder.append_eci(Eci::Shift_JIS);
+    builder.append_eci(Eci::UTF8);
+    builder.append_bytes("é".as_bytes());
+    builder.append_eci(Eci::ISO8859_1);
+    builder.append_eci(Eci::Shift_JIS);
+    builder.append_bytes(&CharacterSet::Shift_JIS.encode("日本").unwrap());
+    assert_eq!(
+        vec![(Eci::UTF8, 0..2), (Eci::Shift_JIS, 2..6)],
+        builder.segments()
+    );
+    assert_eq!("é日本", builder.to_string());
+}
+
+#[test]
+fn test_eci_after_last_byte() {
+    let mut builder = ECIStringBuilder::default();
+    builder.append_bytes(b"ab");
+    builder.append_eci(Eci::UTF8);
+    builder.append_bytes("é".as_bytes());
+    builder.append_eci(Eci::Shift_JIS);
+    assert_eq!(
+        vec![(Eci::ISO8859_1, 0..2), (Eci::UTF8, 2..4)],
+        builder.segments()
+    );
+    assert_eq!("abé", builder.to_string());
+
+    // bytes appended later still pick up the trailing ECI
+    builder.append_bytes(&CharacterSet::Shift_JIS.encode("日").unwrap());
+    assert_eq!("abé日", builder.to_string());
+}
+
+#[test]
+fn test_segment_bytes() {
+    let payload: Vec<u8> = vec![0xFF, 0xD8, 0x00, 0x80, 0xC3, 0x28];
+    let mut builder = ECIStringBuilder::default();
+    builder.append_bytes(b"PHOTO:");
+    builder.append_eci(Eci::Binary);
+    builder.append_bytes(&payload);
+
+    assert_eq!(
+        vec![(Eci::ISO8859_1, 0..6), (Eci::Binary, 6..12)],
+        builder.segments()
+    );
+    assert_eq!(Some(b"PHOTO:".as_slice()), builder.segment_bytes(0));
+    assert_eq!(Some(payload.as_slice()), builder.segment_bytes(1));
+    assert_eq!(None, builder.segment_bytes(2));
+}
+
+#[test]
+fn test_char_byte_ranges() {
+    let mut builder = ECIStringBuilder::default();
+    builder.append_bytes(b"A\xE9");
+    builder.append_eci(Eci::UTF8);
+    builder.append_bytes("bé€".as_bytes());
+    builder.append_eci(Eci::Shift_JIS);
+    builder.append_bytes(&CharacterSet::Shift_JIS.encode("日x").unwrap());
+
+    let ranges = builder.char_byte_ranges();
+    assert_eq!(
+        vec![
+            ('A', 0..1),
+            ('é', 1..2),
+            ('b', 2..3),
+            ('é', 3..5),
+            ('€', 5..8),
+            ('日', 8..10),
+            ('x', 10..11),
+        ],
+        ranges
+    );
+    assert_eq!(
+        builder.to_string(),
+        ranges.iter().map(|(c, _)| c).collect::<String>()
+    );
+    for (c, range) in &ranges[2..5] {
+        assert_eq!(c.to_string().as_bytes(), &builder.bytes()[range.clone()]);
+    }
+}
@@ -25,7 +25,7 @@ use std::borrow::Cow;
 use once_cell::unsync::OnceCell;
 
 use crate::common::Result;
-use crate::{Binarizer, Exceptions, LuminanceSource};
+use crate::{Binarizer, Exceptions, LuminanceSource, NotFoundReason};
 
 use super::{BitArray, BitMatrix};
 
@@ -125,6 +125,11 @@ impl<LS: LuminanceSource> Binarizer for GlobalHistogramBinarizer<LS> {
     fn get_height(&self) -> usize {
         self.height
     }
+
+    /// A single entry holding the black point applied to the whole image.
+    fn black_point_map(&self) -> Option<Vec<Vec<u32>>> {
+        Some(vec![vec![Self::matrix_black_point(&self.source).ok()?]])
+    }
 }
 
 impl<LS: LuminanceSource> GlobalHistogramBinarizer<LS> {
@@ -147,24 +152,7 @@ impl<LS: LuminanceSource> GlobalHistogramBinarizer<LS> {
         let height = source.get_height();
         let mut matrix = BitMatrix::new(width as u32, height as u32)?;
 
-        // Quickly calculates the histogram by sampling four rows from the image. This proved to be
-        // more robust on the blackbox tests than sampling a diagonal as we used to do.
-        // self.initArrays(width);
-        let mut localBuckets = [0; LUMINANCE_BUCKETS]; //self.buckets.clone();
-        for y in 1..5 {
-            // for (int y = 1; y < 5; y++) {
-            let row = height * y / 5;
-            let localLuminances = source.get_row(row);
-            let right = (width * 4) / 5;
-            let mut x = width / 5;
-            while x < right {
-                //   for (int x = width / 5; x < right; x++) {
-                let pixel = localLuminances[x];
-                localBuckets[(pixel >> LUMINANCE_SHIFT) as usize] += 1;
-                x += 1;
-            }
-        }
-        let blackPoint = Self::estimateBlackPoint(&localBuckets)?;
+        let blackPoint = Self::matrix_black_point(source)?;
 
         // We delay reading the entire image luminance until the black point estimation succeeds.
         // Although we end up reading four rows twice, it is consistent with our motto of
@@ -185,6 +173,30 @@ impl<LS: LuminanceSource> GlobalHistogramBinarizer<LS> {
         Ok(matrix)
     }
 
+    fn matrix_black_point(source: &LS) -> Result<u32> {
+        let width = source.get_width();
+        let height = source.get_height();
+
+        // Quickly calculates the histogram by sampling four rows from the image. This proved to be
+        // more robust on the blackbox tests than sampling a diagonal as we used to do.
+        // self.initArrays(width);
+        let mut localBuckets = [0; LUMINANCE_BUCKETS]; //self.buckets.clone();
+        for y in 1..5 {
+            // for (int y = 1; y < 5; y++) {
+            let row = height * y / 5;
+            let localLuminances = source.get_row(row);
+            let right = (width * 4) / 5;
+            let mut x = width / 5;
+            while x < right {
+                //   for (int x = width / 5; x < right; x++) {
+                let pixel = localLuminances[x];
+                localBuckets[(pixel >> LUMINANCE_SHIFT) as usize] += 1;
+                x += 1;
+            }
+        }
+        Self::estimateBlackPoint(&localBuckets)
+    }
+
     // fn initArrays(&mut self, luminanceSize: usize) {
     //     // if self.luminances.len() < luminanceSize {
     //     //     self.luminances = ;
@@ -236,7 +248,8 @@ impl<LS: LuminanceSource> GlobalHistogramBinarizer<LS> {
         // If there is too little contrast in the image to pick a meaningful black point, throw rather
         // than waste time trying to decode the image, and risk false positives.
         if secondPeak - firstPeak <= numBuckets / 16 {
-            return Err(Exceptions::not_found_with(
+            return Err(Exceptions::not_found_because(
+                NotFoundReason::LowContrast,
                 "secondPeak - firstPeak <= numBuckets / 16 ",
             ));
         }
@@ -0,0 +1,96 @@
+use std::cell::Cell;
+
+use crate::{Binarizer, Exceptions, Luma8LuminanceSource, LuminanceSource, NotFoundReason};
+
+use super::GlobalHistogramBinarizer;
+
+/// Counts how often each row is fetched from the wrapped source.
+struct CountingSource {
+    inner: Luma8LuminanceSource,
+    row_reads: Cell<usize>,
+}
+
+impl LuminanceSource for CountingSource {
+    fn get_row(&self, y: usize) -> Vec<u8> {
+        self.row_reads.set(self.row_reads.get() + 1);
+        self.inner.get_row(y)
+    }
+
+    fn get_matrix(&self) -> Vec<u8> {
+        self.inner.get_matrix()
+    }
+
+    fn get_width(&self) -> usize {
+        self.inner.get_width()
+    }
+
+    fn get_height(&self) -> usize {
+        self.inner.get_height()
+    }
+
+    fn invert(&mut self) {
+        self.inner.invert()
+    }
+}
+
+#[test]
+fn test_black_row_is_cached_per_row() {
+    let (width, height) = (40, 4);
+    let luminances = (0..width * height)
+        .map(|i| if (i % width) / 5 % 2 == 0 { 0 } else { 255 })
+        .collect();
+    let binarizer = GlobalHistogramBinarizer::new(CountingSource {
+        inner: Luma8LuminanceSource::new(luminances, width as u32, height as u32),
+        row_reads: Cell::new(0),
+    });
+
+    let first = binarizer.get_black_row(2).unwrap().into_owned();
+    assert_eq!(1, binarizer.get_luminance_source().row_reads.get());
+    for _ in 0..3 {
+        assert_eq!(first, *binarizer.get_black_row(2).unwrap());
+    }
+    assert_eq!(1, binarizer.get_luminance_source().row_reads.get());
+
+    binarizer.get_black_row(1).unwrap();
+    assert_eq!(2, binarizer.get_luminance_source().row_reads.get());
+}
+
+#[test]
+fn test_black_point_map_is_uniform() {
+    let (width, height) = (40, 10);
+    let luminances = (0..width * height)
+        .map(|i| if (i % width) / 5 % 2 == 0 { 0 } else { 255 })
+        .collect();
+    let binarizer =
+        GlobalHistogramBinarizer::new(Luma8LuminanceSource::new(luminances, width, height));
+
+    let map = binarizer.black_point_map().expect("must have a map");
+    assert_eq!(1, map.len());
+    assert_eq!(1, map[0].len());
+    assert!(map[0][0] > 0 && map[0][0] < 255);
+}
+
+#[test]
+fn test_low_contrast_reason() {
+    let (width, height) = (40, 10);
+    let luminances = (0..width * height)
+        .map(|i| if (i % width) / 5 % 2 == 0 { 120 } else { 130 })
+        .collect();
+    let binarizer =
+        GlobalHistogramBinarizer::new(Luma8LuminanceSource::new(luminances, width, height));
+
+    assert!(matches!(
+        binarizer.get_black_row(3),
+        Err(Exceptions::NotFoundException(
+            NotFoundReason::LowContrast,
+            _
+        ))
+    ));
+    assert!(matches!(
+        binarizer.get_black_matrix(),
+        Err(Exceptions::NotFoundException(
+            NotFoundReason::LowContrast,
+            _
+        ))
+    ));
+}
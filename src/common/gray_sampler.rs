@@ -0,0 +1,90 @@
+use crate::{common::Result, point, Exceptions, LuminanceSource, NotFoundReason, Point};
+
+use super::{check_and_nudge_points, PerspectiveTransform};
+
+/// Samples the luminance at the center of each module of a grid, for decoders that make soft
+/// decisions instead of working on a thresholded [`super::BitMatrix`].
+///
+/// Sampling follows the same points as [`super::DefaultGridSampler`]: the center of each module
+/// is mapped into the image with a [`PerspectiveTransform`], and endpoints barely off the image
+/// are nudged back onto it with [`check_and_nudge_points`].
+pub struct GraySampler {
+    luminances: Vec<u8>,
+    width: u32,
+    height: u32,
+}
+
+impl GraySampler {
+    /// Wraps a row-major grid of `width` x `height` luminance values.
+    pub fn new(luminances: Vec<u8>, width: u32, height: u32) -> Result<Self> {
+        if luminances.len() != width as usize * height as usize {
+            return Err(Exceptions::illegal_argument_with(format!(
+                "{} luminance values do not make a {width}x{height} image",
+                luminances.len()
+            )));
+        }
+        Ok(Self {
+            luminances,
+            width,
+            height,
+        })
+    }
+
+    pub fn from_luminance_source<L: LuminanceSource>(source: &L) -> Result<Self> {
+        Self::new(
+            source.get_matrix(),
+            source.get_width() as u32,
+            source.get_height() as u32,
+        )
+    }
+
+    /**
+     * Samples a `dimensionX` x `dimensionY` grid, where `transform` maps grid coordinates into the
+     * image.
+     *
+     * @return the luminance of each module, row by row
+     * @throws NotFoundException if a module center lies outside the image
+     */
+    pub fn sample_gray(
+        &self,
+        dimensionX: u32,
+        dimensionY: u32,
+        transform: &PerspectiveTransform,
+    ) -> Result<Vec<u8>> {
+        if dimensionX == 0 || dimensionY == 0 {
+            return Err(Exceptions::not_found_because(
+                NotFoundReason::DimensionMismatch,
+                format!("cannot sample a {dimensionX}x{dimensionY} grid"),
+            ));
+        }
+        let mut gray = Vec::with_capacity(dimensionX as usize * dimensionY as usize);
+        let mut points = vec![Point::default(); dimensionX as usize];
+        for y in 0..dimensionY {
+            for (x, p) in points.iter_mut().enumerate() {
+                *p = point(x as f32 + 0.5, y as f32 + 0.5);
+            }
+            transform.transform_points_single(&mut points);
+            check_and_nudge_points(self.width, self.height, &mut points)?;
+            for p in &points {
+                gray.push(self.luminance_at(*p).ok_or_else(|| {
+                    Exceptions::not_found_because(
+                        NotFoundReason::SamplingOutOfBounds,
+                        format!("point ({}, {}) lies outside the image", p.x, p.y),
+                    )
+                })?);
+            }
+        }
+        Ok(gray)
+    }
+
+    fn luminance_at(&self, p: Point) -> Option<u8> {
+        if !p.x.is_finite() || !p.y.is_finite() {
+            return None;
+        }
+        let (x, y) = (p.x as i64, p.y as i64);
+        if x < 0 || y < 0 || x >= self.width as i64 || y >= self.height as i64 {
+            return None;
+        }
+        Some(self.luminances[y as usize * self.width as usize + x as usize])
+    }
+}
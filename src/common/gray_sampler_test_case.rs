@@ -0,0 +1,77 @@
+use crate::{point, Exceptions, Luma8LuminanceSource, NotFoundReason};
+
+use super::{GraySampler, PerspectiveTransform, Quadrilateral};
+
+/// A 60x40 image of 10x10 pixel modules, brightening by 20 per module to the right and by 10
+/// per module downwards.
+fn gradient() -> Vec<u8> {
+    (0..60 * 40)
+        .map(|i| (20 * (i % 60 / 10) + 10 * (i / 60 / 10)) as u8)
+        .collect()
+}
+
+fn grid_to_image(
+    dimensionX: f32,
+    dimensionY: f32,
+    width: f32,
+    height: f32,
+) -> PerspectiveTransform {
+    PerspectiveTransform::quadrilateralToQuadrilateral(
+        Quadrilateral::new(
+            point(0.0, 0.0),
+            point(dimensionX, 0.0),
+            point(dimensionX, dimensionY),
+            point(0.0, dimensionY),
+        ),
+        Quadrilateral::new(
+            point(0.0, 0.0),
+            point(width, 0.0),
+            point(width, height),
+            point(0.0, height),
+        ),
+    )
+    .unwrap()
+}
+
+#[test]
+fn test_sample_gradient() {
+    let sampler = GraySampler::new(gradient(), 60, 40).unwrap();
+    let gray = sampler
+        .sample_gray(6, 4, &grid_to_image(6.0, 4.0, 60.0, 40.0))
+        .unwrap();
+    let expected: Vec<u8> = (0..4)
+        .flat_map(|y| (0..6).map(move |x| 20 * x + 10 * y))
+        .collect();
+    assert_eq!(expected, gray);
+}
+
+#[test]
+fn test_from_luminance_source() {
+    let source = Luma8LuminanceSource::new(gradient(), 60, 40);
+    let sampler = GraySampler::from_luminance_source(&source).unwrap();
+    // three modules across the top half of the image; their centers land in gradient modules
+    // (1, 1), (3, 1) and (5, 1)
+    assert_eq!(
+        vec![30, 70, 110],
+        sampler
+            .sample_gray(3, 1, &grid_to_image(3.0, 1.0, 60.0, 20.0))
+            .unwrap()
+    );
+}
+
+#[test]
+fn test_sample_outside_image() {
+    assert!(GraySampler::new(gradient(), 60, 41).is_err());
+
+    let sampler = GraySampler::new(gradient(), 60, 40).unwrap();
+    assert!(matches!(
+        sampler.sample_gray(6, 4, &grid_to_image(6.0, 4.0, 120.0, 40.0)),
+        Err(Exceptions::NotFoundException(
+            NotFoundReason::SamplingOutOfBounds,
+            _
+        ))
+    ));
+    assert!(sampler
+        .sample_gray(0, 4, &grid_to_image(6.0, 4.0, 60.0, 40.0))
+        .is_err());
+}
@@ -18,10 +18,14 @@
 
 // import com.google.zxing.NotFoundException;
 
+use std::sync::{Arc, PoisonError, RwLock};
+
+use once_cell::sync::Lazy;
+
 use crate::{common::Result, Point};
-use crate::{point, Exceptions};
+use crate::{point, Exceptions, NotFoundReason};
 
-use super::{BitMatrix, PerspectiveTransform, Quadrilateral};
+use super::{BitMatrix, DefaultGridSampler, PerspectiveTransform, Quadrilateral};
 
 /**
  * Implementations of this class can, given locations of finder patterns for a QR code in an
@@ -31,7 +35,7 @@ use super::{BitMatrix, PerspectiveTransform, Quadrilateral};
  * Imaging library, but which may not be available in other environments such as J2ME, and vice
  * versa.
  *
- * The implementation used can be controlled by calling {@link #setGridSampler(GridSampler)}
+ * The implementation used can be controlled by calling {@link GridSamplerProvider::set_grid_sampler}
  * with an instance of a class which implements this interface.
  *
  * @author Sean Owen
@@ -121,64 +125,102 @@ pub trait GridSampler {
      * @throws NotFoundException if an endpoint is lies outside the image boundaries
      */
     fn checkAndNudgePoints(&self, image: &BitMatrix, points: &mut [Point]) -> Result<()> {
-        let width = image.getWidth();
-        let height = image.getHeight();
-        // Check and nudge points from start until we see some that are OK:
-        let mut nudged = true;
-        let max_offset = points.len() - 1; // points.length must be even
-        let mut offset = 0;
-        while offset < max_offset && nudged {
-            // for (int offset = 0; offset < maxOffset && nudged; offset += 2) {
-            let x = points[offset].x as i32;
-            let y = points[offset].y as i32;
-            if x < -1 || x > width as i32 || y < -1 || y > height as i32 {
-                return Err(Exceptions::NOT_FOUND);
-            }
-            nudged = false;
-            if x == -1 {
-                points[offset].x = 0.0;
-                nudged = true;
-            } else if x == width as i32 {
-                points[offset].x = width as f32 - 1.0;
-                nudged = true;
-            }
-            if y == -1 {
-                points[offset].y = 0.0;
-                nudged = true;
-            } else if y == height as i32 {
-                points[offset].y = height as f32 - 1.0;
-                nudged = true;
-            }
-            offset += 1;
+        check_and_nudge_points(image.getWidth(), image.getHeight(), points)
+    }
+}
+
+/// The check of [`GridSampler::checkAndNudgePoints`] against an image of `width` x `height`
+/// pixels, for samplers that do not work on a [`BitMatrix`].
+pub fn check_and_nudge_points(width: u32, height: u32, points: &mut [Point]) -> Result<()> {
+    // Check and nudge points from start until we see some that are OK:
+    let mut nudged = true;
+    let max_offset = points.len() - 1; // points.length must be even
+    let mut offset = 0;
+    while offset < max_offset && nudged {
+        // for (int offset = 0; offset < maxOffset && nudged; offset += 2) {
+        let x = points[offset].x as i32;
+        let y = points[offset].y as i32;
+        if x < -1 || x > width as i32 || y < -1 || y > height as i32 {
+            return Err(Exceptions::not_found_because(
+                NotFoundReason::SamplingOutOfBounds,
+                format!("point ({x}, {y}) lies outside the {width}x{height} image"),
+            ));
+        }
+        nudged = false;
+        if x == -1 {
+            points[offset].x = 0.0;
+            nudged = true;
+        } else if x == width as i32 {
+            points[offset].x = width as f32 - 1.0;
+            nudged = true;
+        }
+        if y == -1 {
+            points[offset].y = 0.0;
+            nudged = true;
+        } else if y == height as i32 {
+            points[offset].y = height as f32 - 1.0;
+            nudged = true;
+        }
+        offset += 1;
+    }
+    // Check and nudge points from end:
+    nudged = true;
+    let mut offset = points.len() as isize - 1;
+    while offset >= 0 && nudged {
+        // for (int offset = points.length - 2; offset >= 0 && nudged; offset -= 2) {
+        let x = points[offset as usize].x as i32;
+        let y = points[offset as usize].y as i32;
+        if x < -1 || x > width as i32 || y < -1 || y > height as i32 {
+            return Err(Exceptions::not_found_because(
+                NotFoundReason::SamplingOutOfBounds,
+                format!("point ({x}, {y}) lies outside the {width}x{height} image"),
+            ));
         }
-        // Check and nudge points from end:
-        nudged = true;
-        let mut offset = points.len() as isize - 1;
-        while offset >= 0 && nudged {
-            // for (int offset = points.length - 2; offset >= 0 && nudged; offset -= 2) {
-            let x = points[offset as usize].x as i32;
-            let y = points[offset as usize].y as i32;
-            if x < -1 || x > width as i32 || y < -1 || y > height as i32 {
-                return Err(Exceptions::NOT_FOUND);
-            }
-            nudged = false;
-            if x == -1 {
-                points[offset as usize].x = 0.0;
-                nudged = true;
-            } else if x == width as i32 {
-                points[offset as usize].x = width as f32 - 1.0;
-                nudged = true;
-            }
-            if y == -1 {
-                points[offset as usize].y = 0.0;
-                nudged = true;
-            } else if y == height as i32 {
-                points[offset as usize].y = height as f32 - 1.0;
-                nudged = true;
-            }
-            offset += -1;
+        nudged = false;
+        if x == -1 {
+            points[offset as usize].x = 0.0;
+            nudged = true;
+        } else if x == width as i32 {
+            points[offset as usize].x = width as f32 - 1.0;
+            nudged = true;
         }
-        Ok(())
+        if y == -1 {
+            points[offset as usize].y = 0.0;
+            nudged = true;
+        } else if y == height as i32 {
+            points[offset as usize].y = height as f32 - 1.0;
+            nudged = true;
+        }
+        offset += -1;
+    }
+    Ok(())
+}
+
+static GRID_SAMPLER: Lazy<RwLock<Arc<dyn GridSampler + Send + Sync>>> =
+    Lazy::new(|| RwLock::new(Arc::new(DefaultGridSampler::default())));
+
+/// Holds the process-wide [`GridSampler`] used by the detectors, in place of the static
+/// `GridSampler.setGridSampler` / `GridSampler.getInstance` of the Java implementation.
+///
+/// Until [`GridSamplerProvider::set_grid_sampler`] is called this is a [`DefaultGridSampler`].
+pub struct GridSamplerProvider;
+
+impl GridSamplerProvider {
+    /// Installs `sampler` as the implementation used by the library from now on.
+    ///
+    /// An integrator can use this to swap in an implementation that takes advantage of
+    /// platform-specific optimizations. Decodes already running keep the sampler they started
+    /// with, which is dropped once the last of them is done with it.
+    pub fn set_grid_sampler(sampler: Arc<dyn GridSampler + Send + Sync>) {
+        *GRID_SAMPLER.write().unwrap_or_else(PoisonError::into_inner) = sampler;
+    }
+
+    /// The implementation of [`GridSampler`] currently in use.
+    pub fn get_grid_sampler() -> Arc<dyn GridSampler + Send + Sync> {
+        GRID_SAMPLER
+            .read()
+            .unwrap_or_else(PoisonError::into_inner)
+            .clone()
     }
 }
 
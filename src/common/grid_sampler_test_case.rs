@@ -0,0 +1,76 @@
+use std::sync::{
+    atomic::{AtomicUsize, Ordering},
+    Arc, Mutex, PoisonError,
+};
+
+use crate::{
+    common::Result,
+    qrcode::{detector::Detector, QRCodeWriter},
+    BarcodeFormat, Writer,
+};
+
+use super::{
+    BitMatrix, DefaultGridSampler, GridSampler, GridSamplerProvider, Quadrilateral, SamplerControl,
+};
+
+static CALLS: AtomicUsize = AtomicUsize::new(0);
+
+/// Counts its calls and otherwise behaves like [`DefaultGridSampler`], so that other tests
+/// running while it is installed are unaffected.
+struct CountingGridSampler;
+
+impl GridSampler for CountingGridSampler {
+    fn sample_grid_detailed(
+        &self,
+        image: &BitMatrix,
+        dimensionX: u32,
+        dimensionY: u32,
+        dst: Quadrilateral,
+        src: Quadrilateral,
+    ) -> Result<BitMatrix> {
+        CALLS.fetch_add(1, Ordering::SeqCst);
+        DefaultGridSampler::default().sample_grid_detailed(image, dimensionX, dimensionY, dst, src)
+    }
+
+    fn sample_grid(
+        &self,
+        image: &BitMatrix,
+        dimensionX: u32,
+        dimensionY: u32,
+        controls: &[SamplerControl],
+    ) -> Result<BitMatrix> {
+        CALLS.fetch_add(1, Ordering::SeqCst);
+        DefaultGridSampler::default().sample_grid(image, dimensionX, dimensionY, controls)
+    }
+}
+
+// the tests below swap the process-wide sampler, so they must not run concurrently
+static PROVIDER_LOCK: Mutex<()> = Mutex::new(());
+
+#[test]
+fn test_installed_grid_sampler_is_used() {
+    let _guard = PROVIDER_LOCK.lock().unwrap_or_else(PoisonError::into_inner);
+    let image = QRCodeWriter
+        .encode("grid sampler", &BarcodeFormat::QR_CODE, 200, 200)
+        .unwrap();
+
+    GridSamplerProvider::set_grid_sampler(Arc::new(CountingGridSampler));
+    let before = CALLS.load(Ordering::SeqCst);
+    let detected = Detector::new(&image).detect();
+    let after = CALLS.load(Ordering::SeqCst);
+    GridSamplerProvider::set_grid_sampler(Arc::new(DefaultGridSampler::default()));
+
+    assert!(detected.is_ok());
+    assert!(after > before);
+}
+
+#[test]
+fn test_replaced_grid_sampler_is_dropped() {
+    let _guard = PROVIDER_LOCK.lock().unwrap_or_else(PoisonError::into_inner);
+    let sampler: Arc<dyn GridSampler + Send + Sync> = Arc::new(DefaultGridSampler::default());
+    GridSamplerProvider::set_grid_sampler(sampler.clone());
+    assert_eq!(2, Arc::strong_count(&sampler));
+
+    GridSamplerProvider::set_grid_sampler(Arc::new(DefaultGridSampler::default()));
+    assert_eq!(1, Arc::strong_count(&sampler));
+}
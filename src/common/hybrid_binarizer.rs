@@ -87,6 +87,24 @@ impl<LS: LuminanceSource> Binarizer for HybridBinarizer<LS> {
     fn get_height(&self) -> usize {
         self.ghb.get_height()
     }
+
+    /// The black point of each 8x8 pixel block, before averaging with its neighbours. Images too
+    /// small for local thresholding report the global histogram black point instead.
+    fn black_point_map(&self) -> Option<Vec<Vec<u32>>> {
+        let source = self.ghb.get_luminance_source();
+        let (width, height) = (source.get_width(), source.get_height());
+        if width < MINIMUM_DIMENSION || height < MINIMUM_DIMENSION {
+            return self.ghb.black_point_map();
+        }
+        let (sub_width, sub_height) = Self::block_counts(width, height);
+        Some(Self::calculateBlackPoints(
+            &source.get_matrix(),
+            sub_width as u32,
+            sub_height as u32,
+            width as u32,
+            height as u32,
+        ))
+    }
 }
 
 // This class uses 5x5 blocks to compute local luminance, where each block is 8x8 pixels.
@@ -115,14 +133,7 @@ impl<LS: LuminanceSource> HybridBinarizer<LS> {
         let height = source.get_height();
         let matrix = if width >= MINIMUM_DIMENSION && height >= MINIMUM_DIMENSION {
             let luminances = source.get_matrix();
-            let mut sub_width = width >> BLOCK_SIZE_POWER;
-            if (width & BLOCK_SIZE_MASK) != 0 {
-                sub_width += 1;
-            }
-            let mut sub_height = height >> BLOCK_SIZE_POWER;
-            if (height & BLOCK_SIZE_MASK) != 0 {
-                sub_height += 1;
-            }
+            let (sub_width, sub_height) = Self::block_counts(width, height);
             let black_points = Self::calculateBlackPoints(
                 &luminances,
                 sub_width as u32,
@@ -151,6 +162,19 @@ impl<LS: LuminanceSource> HybridBinarizer<LS> {
         matrix
     }
 
+    /// The number of blocks across and down an image, counting partial blocks.
+    fn block_counts(width: usize, height: usize) -> (usize, usize) {
+        let mut sub_width = width >> BLOCK_SIZE_POWER;
+        if (width & BLOCK_SIZE_MASK) != 0 {
+            sub_width += 1;
+        }
+        let mut sub_height = height >> BLOCK_SIZE_POWER;
+        if (height & BLOCK_SIZE_MASK) != 0 {
+            sub_height += 1;
+        }
+        (sub_width, sub_height)
+    }
+
     /**
      * For each block in the image, calculate the average black point using a 5x5 grid
      * of the blocks around it. Also handles the corner cases (fractional blocks are computed based
@@ -0,0 +1,21 @@
+use crate::{Binarizer, Luma8LuminanceSource};
+
+use super::HybridBinarizer;
+
+#[test]
+fn test_black_point_map_of_large_image() {
+    let (width, height) = (100, 80);
+    let luminances = (0..width * height)
+        .map(|i| if (i % width) / 5 % 2 == 0 { 20 } else { 230 })
+        .collect();
+    let binarizer = HybridBinarizer::new(Luma8LuminanceSource::new(luminances, width, height));
+
+    let map = binarizer.black_point_map().expect("must have a map");
+    // 8x8 pixel blocks, counting the partial blocks on the right
+    assert_eq!(10, map.len());
+    assert!(map.iter().all(|row| row.len() == 13));
+    assert!(map
+        .iter()
+        .flatten()
+        .all(|black_point| (20..230).contains(black_point)));
+}
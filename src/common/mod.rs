@@ -18,6 +18,25 @@ mod BitSourceTestCase;
 #[cfg(test)]
 mod PerspectiveTransformTestCase;
 
+#[cfg(test)]
+mod eci_string_builder_test_case;
+
+#[cfg(test)]
+mod character_set_test_case;
+
+#[cfg(test)]
+mod decoder_rxing_result_test_case;
+#[cfg(test)]
+mod global_histogram_binarizer_test_case;
+#[cfg(test)]
+mod hybrid_binarizer_test_case;
+#[cfg(test)]
+mod default_grid_sampler_test_case;
+#[cfg(test)]
+mod grid_sampler_test_case;
+#[cfg(test)]
+mod gray_sampler_test_case;
+
 mod string_utils;
 pub use string_utils::*;
 
@@ -76,6 +95,9 @@ pub use bit_source::*;
 mod perspective_transform;
 pub use perspective_transform::*;
 
+mod perspective_transform_f64;
+pub use perspective_transform_f64::*;
+
 mod decoder_rxing_result;
 pub use decoder_rxing_result::*;
 
@@ -88,6 +110,9 @@ pub use grid_sampler::*;
 mod default_grid_sampler;
 pub use default_grid_sampler::*;
 
+mod gray_sampler;
+pub use gray_sampler::*;
+
 mod character_set;
 pub use character_set::*;
 
@@ -112,6 +137,12 @@ pub use eci::*;
 mod quad;
 pub use quad::*;
 
+mod rect;
+pub use rect::*;
+
+#[cfg(test)]
+mod rect_test_case;
+
 #[cfg(feature = "otsu_level")]
 mod otsu_level_binarizer;
 #[cfg(feature = "otsu_level")]
@@ -81,14 +81,55 @@ impl PerspectiveTransform {
         Ok(s_to_q * q_to_s)
     }
 
+    /// Like [`PerspectiveTransform::quadrilateralToQuadrilateral`], but first rejects degenerate
+    /// quadrilaterals, such as ones with collinear corners, whose transform would divide by a
+    /// (near) zero denominator and yield NaN or infinite coefficients.
+    pub fn quadrilateral_to_quadrilateral_checked(
+        dst: Quadrilateral,
+        src: Quadrilateral,
+    ) -> Result<Self> {
+        Self::check_non_singular(&dst)?;
+        Self::check_non_singular(&src)?;
+        Self::quadrilateralToQuadrilateral(dst, src)
+    }
+
+    fn check_non_singular(quad: &Quadrilateral) -> Result<()> {
+        let [p0, p1, p2, p3] = quad.0;
+        // the vectors whose cross product squareToQuadrilateral depends on
+        let (u, v) = if p0 - p1 + p2 - p3 == point(0.0, 0.0) {
+            (p1 - p0, p2 - p1)
+        } else {
+            (p1 - p2, p3 - p2)
+        };
+        let denominator = u.cross(v);
+        if denominator.is_nan() || denominator.abs() <= f32::EPSILON * u.length() * v.length() {
+            return Err(Exceptions::not_found_with("degenerate quadrilateral"));
+        }
+        if !Self::squareToQuadrilateral(*quad).determinant().is_normal() {
+            return Err(Exceptions::not_found_with("singular perspective transform"));
+        }
+        Ok(())
+    }
+
+    fn determinant(&self) -> f32 {
+        self.a11 * (self.a22 * self.a33 - self.a23 * self.a32)
+            - self.a21 * (self.a12 * self.a33 - self.a13 * self.a32)
+            + self.a31 * (self.a12 * self.a23 - self.a13 * self.a22)
+    }
+
+    /// Maps a single `(x, y)` source coordinate through this transform.
+    pub fn transform_point(&self, x: f32, y: f32) -> (f32, f32) {
+        let denominator = self.a13 * x + self.a23 * y + self.a33;
+        (
+            (self.a11 * x + self.a21 * y + self.a31) / denominator,
+            (self.a12 * x + self.a22 * y + self.a32) / denominator,
+        )
+    }
+
     pub fn transform_points_single(&self, points: &mut [Point]) {
         for point in points.iter_mut() {
             // for (int i = 0; i < maxI; i += 2) {
-            let x = point.x;
-            let y = point.y;
-            let denominator = self.a13 * x + self.a23 * y + self.a33;
-            point.x = (self.a11 * x + self.a21 * y + self.a31) / denominator;
-            point.y = (self.a12 * x + self.a22 * y + self.a32) / denominator;
+            (point.x, point.y) = self.transform_point(point.x, point.y);
         }
     }
 
@@ -97,9 +138,7 @@ impl PerspectiveTransform {
         // for i in 0..n {
         for (x, y) in x_values.iter_mut().zip(y_valuess.iter_mut()).take(n) {
             // for (int i = 0; i < n; i++) {
-            let denominator = self.a13 * *x + self.a23 * *y + self.a33;
-            *x = (self.a11 * *x + self.a21 * *y + self.a31) / denominator;
-            *y = (self.a12 * *x + self.a22 * *y + self.a32) / denominator;
+            (*x, *y) = self.transform_point(*x, *y);
         }
     }
 
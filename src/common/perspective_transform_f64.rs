@@ -0,0 +1,160 @@
+use std::ops::Mul;
+
+use crate::{common::Result, Exceptions, Point};
+
+use super::Quadrilateral;
+
+/// A [`PerspectiveTransform`](super::PerspectiveTransform) computed and applied in `f64`.
+///
+/// Building a transform multiplies coordinates together several times, so on very large images
+/// the rounding of `f32` can shift sampled points by whole modules. This variant keeps the
+/// intermediate values in double precision and only rounds the final coordinates to `f32`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PerspectiveTransformF64 {
+    a11: f64,
+    a12: f64,
+    a13: f64,
+    a21: f64,
+    a22: f64,
+    a23: f64,
+    a31: f64,
+    a32: f64,
+    a33: f64,
+}
+
+impl PerspectiveTransformF64 {
+    #[allow(clippy::too_many_arguments)]
+    fn new(
+        a11: f64,
+        a21: f64,
+        a31: f64,
+        a12: f64,
+        a22: f64,
+        a32: f64,
+        a13: f64,
+        a23: f64,
+        a33: f64,
+    ) -> Self {
+        Self {
+            a11,
+            a12,
+            a13,
+            a21,
+            a22,
+            a23,
+            a31,
+            a32,
+            a33,
+        }
+    }
+
+    pub fn quadrilateralToQuadrilateral(dst: Quadrilateral, src: Quadrilateral) -> Result<Self> {
+        if !src.is_convex() || !dst.is_convex() {
+            return Err(Exceptions::ILLEGAL_STATE);
+        }
+
+        let q_to_s = Self::quadrilateralToSquare(dst);
+        let s_to_q = Self::squareToQuadrilateral(src);
+        Ok(s_to_q * q_to_s)
+    }
+
+    /// Maps a single `(x, y)` source coordinate through this transform.
+    pub fn transform_point(&self, x: f64, y: f64) -> (f64, f64) {
+        let denominator = self.a13 * x + self.a23 * y + self.a33;
+        (
+            (self.a11 * x + self.a21 * y + self.a31) / denominator,
+            (self.a12 * x + self.a22 * y + self.a32) / denominator,
+        )
+    }
+
+    pub fn transform_points_single(&self, points: &mut [Point]) {
+        for point in points.iter_mut() {
+            let (x, y) = self.transform_point(point.x as f64, point.y as f64);
+            point.x = x as f32;
+            point.y = y as f32;
+        }
+    }
+
+    pub fn transform_points_double(&self, x_values: &mut [f64], y_values: &mut [f64]) {
+        for (x, y) in x_values.iter_mut().zip(y_values.iter_mut()) {
+            (*x, *y) = self.transform_point(*x, *y);
+        }
+    }
+
+    pub fn squareToQuadrilateral(square: Quadrilateral) -> Self {
+        let [p0, p1, p2, p3] = square.0.map(|p| (p.x as f64, p.y as f64));
+        let cross = |a: (f64, f64), b: (f64, f64)| a.0 * b.1 - b.0 * a.1;
+
+        let d3 = (p0.0 - p1.0 + p2.0 - p3.0, p0.1 - p1.1 + p2.1 - p3.1);
+        if d3 == (0.0, 0.0) {
+            // Affine
+            Self::new(
+                p1.0 - p0.0,
+                p2.0 - p1.0,
+                p0.0,
+                p1.1 - p0.1,
+                p2.1 - p1.1,
+                p0.1,
+                0.0,
+                0.0,
+                1.0,
+            )
+        } else {
+            let d1 = (p1.0 - p2.0, p1.1 - p2.1);
+            let d2 = (p3.0 - p2.0, p3.1 - p2.1);
+
+            let denominator = cross(d1, d2);
+            let a13 = cross(d3, d2) / denominator;
+            let a23 = cross(d1, d3) / denominator;
+            Self::new(
+                p1.0 - p0.0 + a13 * p1.0,
+                p3.0 - p0.0 + a23 * p3.0,
+                p0.0,
+                p1.1 - p0.1 + a13 * p1.1,
+                p3.1 - p0.1 + a23 * p3.1,
+                p0.1,
+                a13,
+                a23,
+                1.0,
+            )
+        }
+    }
+
+    pub fn quadrilateralToSquare(quad: Quadrilateral) -> Self {
+        // Here, the adjoint serves as the inverse
+        Self::squareToQuadrilateral(quad).buildAdjoint()
+    }
+
+    fn buildAdjoint(&self) -> Self {
+        // Adjoint is the transpose of the cofactor matrix:
+        Self::new(
+            self.a22 * self.a33 - self.a23 * self.a32,
+            self.a23 * self.a31 - self.a21 * self.a33,
+            self.a21 * self.a32 - self.a22 * self.a31,
+            self.a13 * self.a32 - self.a12 * self.a33,
+            self.a11 * self.a33 - self.a13 * self.a31,
+            self.a12 * self.a31 - self.a11 * self.a32,
+            self.a12 * self.a23 - self.a13 * self.a22,
+            self.a13 * self.a21 - self.a11 * self.a23,
+            self.a11 * self.a22 - self.a12 * self.a21,
+        )
+    }
+}
+
+impl Mul for PerspectiveTransformF64 {
+    type Output = PerspectiveTransformF64;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        Self::new(
+            self.a11 * rhs.a11 + self.a21 * rhs.a12 + self.a31 * rhs.a13,
+            self.a11 * rhs.a21 + self.a21 * rhs.a22 + self.a31 * rhs.a23,
+            self.a11 * rhs.a31 + self.a21 * rhs.a32 + self.a31 * rhs.a33,
+            self.a12 * rhs.a11 + self.a22 * rhs.a12 + self.a32 * rhs.a13,
+            self.a12 * rhs.a21 + self.a22 * rhs.a22 + self.a32 * rhs.a23,
+            self.a12 * rhs.a31 + self.a22 * rhs.a32 + self.a32 * rhs.a33,
+            self.a13 * rhs.a11 + self.a23 * rhs.a12 + self.a33 * rhs.a13,
+            self.a13 * rhs.a21 + self.a23 * rhs.a22 + self.a33 * rhs.a23,
+            self.a13 * rhs.a31 + self.a23 * rhs.a32 + self.a33 * rhs.a33,
+        )
+    }
+}
@@ -0,0 +1,57 @@
+use crate::{point, Point};
+
+/// An axis aligned rectangle in matrix coordinates, given by its top-left corner and size.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct Rect {
+    pub left: u32,
+    pub top: u32,
+    pub width: u32,
+    pub height: u32,
+}
+
+impl Rect {
+    pub fn new(left: u32, top: u32, width: u32, height: u32) -> Self {
+        Self {
+            left,
+            top,
+            width,
+            height,
+        }
+    }
+
+    /// The number of cells covered by this rectangle.
+    pub fn area(&self) -> u32 {
+        self.width * self.height
+    }
+
+    /// Whether `p` lies within this rectangle. The left and top edges are inside it, the right
+    /// and bottom edges (at `left + width` and `top + height`) are not.
+    pub fn contains(&self, p: Point) -> bool {
+        // f64 holds the far edges exactly, even past u32::MAX
+        let (x, y) = (p.x as f64, p.y as f64);
+        x >= self.left as f64
+            && y >= self.top as f64
+            && x < self.left as f64 + self.width as f64
+            && y < self.top as f64 + self.height as f64
+    }
+
+    /// The center of this rectangle, which need not lie on a whole coordinate.
+    pub fn center(&self) -> Point {
+        point(
+            self.left as f32 + self.width as f32 / 2.0,
+            self.top as f32 + self.height as f32 / 2.0,
+        )
+    }
+
+    /// This rectangle with its position and size multiplied by `factor`, e.g. to map a
+    /// rectangle of modules onto the pixels of an image rendered `factor` pixels per module.
+    /// Returns `None` if any of them overflows.
+    pub fn scale(&self, factor: u32) -> Option<Self> {
+        Some(Self::new(
+            self.left.checked_mul(factor)?,
+            self.top.checked_mul(factor)?,
+            self.width.checked_mul(factor)?,
+            self.height.checked_mul(factor)?,
+        ))
+    }
+}
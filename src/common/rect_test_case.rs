@@ -0,0 +1,38 @@
+use crate::point;
+
+use super::Rect;
+
+#[test]
+fn test_contains_at_edges() {
+    let rect = Rect::new(2, 3, 4, 5);
+    assert!(rect.contains(point(2.0, 3.0)));
+    assert!(rect.contains(point(5.9, 7.9)));
+    assert!(!rect.contains(point(6.0, 4.0)));
+    assert!(!rect.contains(point(3.0, 8.0)));
+    assert!(!rect.contains(point(1.9, 4.0)));
+    assert!(!rect.contains(point(3.0, 2.9)));
+
+    // the right and bottom edges lie past u32::MAX
+    let rect = Rect::new(u32::MAX - 1023, u32::MAX - 1023, 2048, 2048);
+    assert!(rect.contains(point(4294967296.0, 4294967296.0)));
+    assert!(!rect.contains(point(4294968320.0, 4294967296.0)));
+    assert!(!rect.contains(point(0.0, 0.0)));
+}
+
+#[test]
+fn test_center() {
+    assert_eq!(point(4.0, 5.5), Rect::new(2, 3, 4, 5).center());
+    assert_eq!(point(0.5, 0.5), Rect::new(0, 0, 1, 1).center());
+}
+
+#[test]
+fn test_scale() {
+    let modules = Rect::new(2, 3, 4, 5);
+    let pixels = modules.scale(3).unwrap();
+    assert_eq!(Rect::new(6, 9, 12, 15), pixels);
+    assert_eq!(modules.center() * 3.0, pixels.center());
+    assert_eq!(Some(modules), modules.scale(1));
+
+    assert_eq!(None, Rect::new(0, 0, u32::MAX / 2 + 1, 1).scale(2));
+    assert_eq!(None, Rect::new(u32::MAX, 0, 1, 1).scale(2));
+}
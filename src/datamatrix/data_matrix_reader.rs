@@ -180,19 +180,17 @@ impl DataMatrixReader {
      * case.
      */
     fn extractPureBits(&self, image: &BitMatrix) -> Result<BitMatrix> {
-        let Some(leftTopBlack) = image.getTopLeftOnBit() else {
+        let Some(leftTopBlack) = image.top_left_on_bit() else {
       return Err(Exceptions::NOT_FOUND)
     };
-        let Some(rightBottomBlack) = image.getBottomRightOnBit()else {
+        let Some(rightBottomBlack) = image.bottom_right_on_bit()else {
       return Err(Exceptions::NOT_FOUND)
     };
 
-        let moduleSize = Self::moduleSize(&leftTopBlack, image)?;
+        let moduleSize = Self::moduleSize(leftTopBlack, image)?;
 
-        let mut top = leftTopBlack[1];
-        let bottom = rightBottomBlack[1];
-        let mut left = leftTopBlack[0];
-        let right = rightBottomBlack[0];
+        let (mut left, mut top) = leftTopBlack;
+        let (right, bottom) = rightBottomBlack;
 
         let matrixWidth = (right as i32 - left as i32 + 1) / moduleSize as i32;
         let matrixHeight = (bottom as i32 - top as i32 + 1) / moduleSize as i32;
@@ -226,10 +224,9 @@ impl DataMatrixReader {
         Ok(bits)
     }
 
-    fn moduleSize(leftTopBlack: &[u32], image: &BitMatrix) -> Result<u32> {
+    fn moduleSize(leftTopBlack: (u32, u32), image: &BitMatrix) -> Result<u32> {
         let width = image.getWidth();
-        let mut x = leftTopBlack[0];
-        let y = leftTopBlack[1];
+        let (mut x, y) = leftTopBlack;
         while x < width && image.get(x, y) {
             x += 1;
         }
@@ -237,7 +234,7 @@ impl DataMatrixReader {
             return Err(Exceptions::NOT_FOUND);
         }
 
-        let moduleSize = x - leftTopBlack[0];
+        let moduleSize = x - leftTopBlack.0;
         if moduleSize == 0 {
             return Err(Exceptions::NOT_FOUND);
         }
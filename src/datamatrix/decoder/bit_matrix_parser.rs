@@ -16,6 +16,7 @@
 
 use crate::{
     common::{BitMatrix, Result},
+    datamatrix::encoder::datamatrix_placement_order,
     Exceptions,
 };
 
@@ -26,7 +27,6 @@ use super::{Version, VersionRef};
  */
 pub struct BitMatrixParser {
     mappingBitMatrix: BitMatrix,
-    readMappingMatrix: BitMatrix,
     version: VersionRef,
 }
 impl BitMatrixParser {
@@ -42,12 +42,9 @@ impl BitMatrixParser {
 
         let version = Self::readVersion(bitMatrix)?;
         let mappingBitMatrix = Self::extractDataRegion(bitMatrix, version)?;
-        let readMappingMatrix =
-            BitMatrix::new(mappingBitMatrix.getWidth(), mappingBitMatrix.getHeight())?;
 
         Ok(Self {
             mappingBitMatrix,
-            readMappingMatrix,
             version,
         })
     }
@@ -78,369 +75,31 @@ impl BitMatrixParser {
      * in the correct order in order to reconstitute the codewords bytes contained within the
      * Data Matrix Code.</p>
      *
+     * <p>The modules are visited in the same order the encoder places them in, see
+     * {@link datamatrix_placement_order}.</p>
+     *
      * @return bytes encoded within the Data Matrix Code
      * @throws FormatException if the exact number of bytes expected is not read
      */
-    pub fn readCodewords(&mut self) -> Result<Vec<u8>> {
-        let mut result = vec![0u8; self.version.getTotalCodewords() as usize];
-        let mut resultOffset = 0;
-
-        let mut row = 4;
-        let mut column = 0;
-
-        let numRows = self.mappingBitMatrix.getHeight() as isize;
-        let numColumns = self.mappingBitMatrix.getWidth() as isize;
-
-        let mut corner1Read = false;
-        let mut corner2Read = false;
-        let mut corner3Read = false;
-        let mut corner4Read = false;
-
-        // Read all of the codewords
-        loop {
-            // Check the four corner cases
-            if (row == numRows) && (column == 0) && !corner1Read {
-                result[resultOffset] = self.readCorner1(numRows, numColumns) as u8;
-                resultOffset += 1;
-                row -= 2;
-                column += 2;
-                corner1Read = true;
-            } else if (row == numRows - 2)
-                && (column == 0)
-                && ((numColumns & 0x03) != 0)
-                && !corner2Read
-            {
-                result[resultOffset] = self.readCorner2(numRows, numColumns) as u8;
-                resultOffset += 1;
-                row -= 2;
-                column += 2;
-                corner2Read = true;
-            } else if (row == numRows + 4)
-                && (column == 2)
-                && ((numColumns & 0x07) == 0)
-                && !corner3Read
-            {
-                result[resultOffset] = self.readCorner3(numRows, numColumns) as u8;
-                resultOffset += 1;
-                row -= 2;
-                column += 2;
-                corner3Read = true;
-            } else if (row == numRows - 2)
-                && (column == 0)
-                && ((numColumns & 0x07) == 4)
-                && !corner4Read
-            {
-                result[resultOffset] = self.readCorner4(numRows, numColumns) as u8;
-                resultOffset += 1;
-                row -= 2;
-                column += 2;
-                corner4Read = true;
-            } else {
-                // Sweep upward diagonally to the right
-                loop {
-                    if (row < numRows)
-                        && (column >= 0)
-                        && !self.readMappingMatrix.get(column as u32, row as u32)
-                    {
-                        result[resultOffset] =
-                            self.readUtah(row, column, numRows, numColumns) as u8;
-                        resultOffset += 1;
-                    }
-                    row -= 2;
-                    column += 2;
-                    if !((row >= 0) && (column < numColumns)) {
-                        break;
-                    }
-                }
-                row += 1;
-                column += 3;
-
-                // Sweep downward diagonally to the left
-                loop {
-                    if (row >= 0)
-                        && (column < numColumns)
-                        && !self.readMappingMatrix.get(column as u32, row as u32)
-                    {
-                        result[resultOffset] =
-                            self.readUtah(row, column, numRows, numColumns) as u8;
-                        resultOffset += 1;
-                    }
-                    row += 2;
-                    column -= 2;
-
-                    if !((row < numRows) && (column >= 0)) {
-                        break;
-                    }
-                }
-                row += 3;
-                column += 1;
-            }
-            if !((row < numRows) || (column < numColumns)) {
-                break;
-            }
-        }
-
-        if resultOffset != self.version.getTotalCodewords() as usize {
+    pub fn readCodewords(&self) -> Result<Vec<u8>> {
+        let order = datamatrix_placement_order(
+            self.mappingBitMatrix.getHeight(),
+            self.mappingBitMatrix.getWidth(),
+        )
+        .map_err(|_| Exceptions::FORMAT)?;
+
+        if order.len() != self.version.getTotalCodewords() as usize * 8 {
             return Err(Exceptions::FORMAT);
         }
 
-        Ok(result)
-    }
-
-    /**
-     * <p>Reads a bit of the mapping matrix accounting for boundary wrapping.</p>
-     *
-     * @param row Row to read in the mapping matrix
-     * @param column Column to read in the mapping matrix
-     * @param numRows Number of rows in the mapping matrix
-     * @param numColumns Number of columns in the mapping matrix
-     * @return value of the given bit in the mapping matrix
-     */
-    fn readModule(&mut self, row: isize, column: isize, numRows: isize, numColumns: isize) -> bool {
-        let mut row = row;
-        let mut column = column;
-        // Adjust the row and column indices based on boundary wrapping
-        if row < 0 {
-            row += numRows;
-            column += 4 - ((numRows + 4) & 0x07);
-        }
-        if column < 0 {
-            column += numColumns;
-            row += 4 - ((numColumns + 4) & 0x07);
-        }
-        if row >= numRows {
-            row -= numRows;
-        }
-        self.readMappingMatrix.set(column as u32, row as u32);
-
-        self.mappingBitMatrix.get(column as u32, row as u32)
-    }
-
-    /**
-     * <p>Reads the 8 bits of the standard Utah-shaped pattern.</p>
-     *
-     * <p>See ISO 16022:2006, 5.8.1 Figure 6</p>
-     *
-     * @param row Current row in the mapping matrix, anchored at the 8th bit (LSB) of the pattern
-     * @param column Current column in the mapping matrix, anchored at the 8th bit (LSB) of the pattern
-     * @param numRows Number of rows in the mapping matrix
-     * @param numColumns Number of columns in the mapping matrix
-     * @return byte from the utah shape
-     */
-    fn readUtah(&mut self, row: isize, column: isize, numRows: isize, numColumns: isize) -> u32 {
-        let mut currentByte = 0;
-        if self.readModule(row - 2, column - 2, numRows, numColumns) {
-            currentByte |= 1;
-        }
-        currentByte <<= 1;
-        if self.readModule(row - 2, column - 1, numRows, numColumns) {
-            currentByte |= 1;
-        }
-        currentByte <<= 1;
-        if self.readModule(row - 1, column - 2, numRows, numColumns) {
-            currentByte |= 1;
-        }
-        currentByte <<= 1;
-        if self.readModule(row - 1, column - 1, numRows, numColumns) {
-            currentByte |= 1;
-        }
-        currentByte <<= 1;
-        if self.readModule(row - 1, column, numRows, numColumns) {
-            currentByte |= 1;
-        }
-        currentByte <<= 1;
-        if self.readModule(row, column - 2, numRows, numColumns) {
-            currentByte |= 1;
-        }
-        currentByte <<= 1;
-        if self.readModule(row, column - 1, numRows, numColumns) {
-            currentByte |= 1;
-        }
-        currentByte <<= 1;
-        if self.readModule(row, column, numRows, numColumns) {
-            currentByte |= 1;
-        }
-        currentByte
-    }
-
-    /**
-     * <p>Reads the 8 bits of the special corner condition 1.</p>
-     *
-     * <p>See ISO 16022:2006, Figure F.3</p>
-     *
-     * @param numRows Number of rows in the mapping matrix
-     * @param numColumns Number of columns in the mapping matrix
-     * @return byte from the Corner condition 1
-     */
-    fn readCorner1(&mut self, numRows: isize, numColumns: isize) -> u32 {
-        let mut currentByte = 0;
-        if self.readModule(numRows - 1, 0, numRows, numColumns) {
-            currentByte |= 1;
-        }
-        currentByte <<= 1;
-        if self.readModule(numRows - 1, 1, numRows, numColumns) {
-            currentByte |= 1;
-        }
-        currentByte <<= 1;
-        if self.readModule(numRows - 1, 2, numRows, numColumns) {
-            currentByte |= 1;
-        }
-        currentByte <<= 1;
-        if self.readModule(0, numColumns - 2, numRows, numColumns) {
-            currentByte |= 1;
-        }
-        currentByte <<= 1;
-        if self.readModule(0, numColumns - 1, numRows, numColumns) {
-            currentByte |= 1;
-        }
-        currentByte <<= 1;
-        if self.readModule(1, numColumns - 1, numRows, numColumns) {
-            currentByte |= 1;
-        }
-        currentByte <<= 1;
-        if self.readModule(2, numColumns - 1, numRows, numColumns) {
-            currentByte |= 1;
-        }
-        currentByte <<= 1;
-        if self.readModule(3, numColumns - 1, numRows, numColumns) {
-            currentByte |= 1;
-        }
-        currentByte
-    }
-
-    /**
-     * <p>Reads the 8 bits of the special corner condition 2.</p>
-     *
-     * <p>See ISO 16022:2006, Figure F.4</p>
-     *
-     * @param numRows Number of rows in the mapping matrix
-     * @param numColumns Number of columns in the mapping matrix
-     * @return byte from the Corner condition 2
-     */
-    fn readCorner2(&mut self, numRows: isize, numColumns: isize) -> u32 {
-        let mut currentByte = 0;
-        if self.readModule(numRows - 3, 0, numRows, numColumns) {
-            currentByte |= 1;
-        }
-        currentByte <<= 1;
-        if self.readModule(numRows - 2, 0, numRows, numColumns) {
-            currentByte |= 1;
-        }
-        currentByte <<= 1;
-        if self.readModule(numRows - 1, 0, numRows, numColumns) {
-            currentByte |= 1;
-        }
-        currentByte <<= 1;
-        if self.readModule(0, numColumns - 4, numRows, numColumns) {
-            currentByte |= 1;
-        }
-        currentByte <<= 1;
-        if self.readModule(0, numColumns - 3, numRows, numColumns) {
-            currentByte |= 1;
-        }
-        currentByte <<= 1;
-        if self.readModule(0, numColumns - 2, numRows, numColumns) {
-            currentByte |= 1;
-        }
-        currentByte <<= 1;
-        if self.readModule(0, numColumns - 1, numRows, numColumns) {
-            currentByte |= 1;
-        }
-        currentByte <<= 1;
-        if self.readModule(1, numColumns - 1, numRows, numColumns) {
-            currentByte |= 1;
-        }
-        currentByte
-    }
-
-    /**
-     * <p>Reads the 8 bits of the special corner condition 3.</p>
-     *
-     * <p>See ISO 16022:2006, Figure F.5</p>
-     *
-     * @param numRows Number of rows in the mapping matrix
-     * @param numColumns Number of columns in the mapping matrix
-     * @return byte from the Corner condition 3
-     */
-    fn readCorner3(&mut self, numRows: isize, numColumns: isize) -> u32 {
-        let mut currentByte = 0;
-        if self.readModule(numRows - 1, 0, numRows, numColumns) {
-            currentByte |= 1;
-        }
-        currentByte <<= 1;
-        if self.readModule(numRows - 1, numColumns - 1, numRows, numColumns) {
-            currentByte |= 1;
-        }
-        currentByte <<= 1;
-        if self.readModule(0, numColumns - 3, numRows, numColumns) {
-            currentByte |= 1;
-        }
-        currentByte <<= 1;
-        if self.readModule(0, numColumns - 2, numRows, numColumns) {
-            currentByte |= 1;
-        }
-        currentByte <<= 1;
-        if self.readModule(0, numColumns - 1, numRows, numColumns) {
-            currentByte |= 1;
-        }
-        currentByte <<= 1;
-        if self.readModule(1, numColumns - 3, numRows, numColumns) {
-            currentByte |= 1;
-        }
-        currentByte <<= 1;
-        if self.readModule(1, numColumns - 2, numRows, numColumns) {
-            currentByte |= 1;
-        }
-        currentByte <<= 1;
-        if self.readModule(1, numColumns - 1, numRows, numColumns) {
-            currentByte |= 1;
-        }
-        currentByte
-    }
-
-    /**
-     * <p>Reads the 8 bits of the special corner condition 4.</p>
-     *
-     * <p>See ISO 16022:2006, Figure F.6</p>
-     *
-     * @param numRows Number of rows in the mapping matrix
-     * @param numColumns Number of columns in the mapping matrix
-     * @return byte from the Corner condition 4
-     */
-    fn readCorner4(&mut self, numRows: isize, numColumns: isize) -> u32 {
-        let mut currentByte = 0;
-        if self.readModule(numRows - 3, 0, numRows, numColumns) {
-            currentByte |= 1;
-        }
-        currentByte <<= 1;
-        if self.readModule(numRows - 2, 0, numRows, numColumns) {
-            currentByte |= 1;
-        }
-        currentByte <<= 1;
-        if self.readModule(numRows - 1, 0, numRows, numColumns) {
-            currentByte |= 1;
-        }
-        currentByte <<= 1;
-        if self.readModule(0, numColumns - 2, numRows, numColumns) {
-            currentByte |= 1;
-        }
-        currentByte <<= 1;
-        if self.readModule(0, numColumns - 1, numRows, numColumns) {
-            currentByte |= 1;
-        }
-        currentByte <<= 1;
-        if self.readModule(1, numColumns - 1, numRows, numColumns) {
-            currentByte |= 1;
-        }
-        currentByte <<= 1;
-        if self.readModule(2, numColumns - 1, numRows, numColumns) {
-            currentByte |= 1;
-        }
-        currentByte <<= 1;
-        if self.readModule(3, numColumns - 1, numRows, numColumns) {
-            currentByte |= 1;
-        }
-        currentByte
+        Ok(order
+            .chunks_exact(8)
+            .map(|codeword| {
+                codeword.iter().fold(0u8, |byte, &(x, y)| {
+                    (byte << 1) | u8::from(self.mappingBitMatrix.get(x, y))
+                })
+            })
+            .collect())
     }
 
     /**
@@ -101,7 +101,7 @@ impl Decoder {
         is_flipped: bool,
     ) -> Result<DecoderRXingResult> {
         // Construct a parser and read version, error-correction level
-        let mut parser = BitMatrixParser::new(bits)?;
+        let parser = BitMatrixParser::new(bits)?;
 
         // Read codewords
         let codewords = parser.readCodewords()?;
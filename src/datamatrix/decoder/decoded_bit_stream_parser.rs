@@ -186,7 +186,7 @@ pub fn decode(bytes: &[u8], is_flipped: bool) -> Result<DecoderRXingResult> {
 
     let mut result = DecoderRXingResult::with_symbology(
         bytes.to_vec(),
-        result.build_result().to_string(),
+        result.build_result()?.to_string(),
         byteSegments,
         String::new(),
         symbologyModifier,
@@ -115,6 +115,22 @@ impl Version {
         Err(Exceptions::FORMAT)
     }
 
+    /// The number of rows and columns of the mapping matrix of this version, i.e. all of its
+    /// data regions put together without their alignment patterns.
+    pub fn getMappingMatrixSize(&self) -> (u32, u32) {
+        (
+            (self.symbolSizeRows / self.dataRegionSizeRows) * self.dataRegionSizeRows,
+            (self.symbolSizeColumns / self.dataRegionSizeColumns) * self.dataRegionSizeColumns,
+        )
+    }
+
+    /// Whether some version has a mapping matrix of `numRows` x `numColumns` modules.
+    pub fn isMappingMatrixSize(numRows: u32, numColumns: u32) -> bool {
+        VERSIONS
+            .iter()
+            .any(|version| version.getMappingMatrixSize() == (numRows, numColumns))
+    }
+
     /**
      * See ISO 16022:2006 5.5.1 Table 7
      */
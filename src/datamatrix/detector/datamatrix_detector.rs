@@ -16,8 +16,7 @@
 
 use crate::{
     common::{
-        detector::WhiteRectangleDetector, BitMatrix, DefaultGridSampler, GridSampler,
-        Quadrilateral, Result,
+        detector::WhiteRectangleDetector, BitMatrix, GridSamplerProvider, Quadrilateral, Result,
     },
     point, Exceptions, Point,
 };
@@ -333,7 +332,7 @@ impl<'a> Detector<'_> {
         dimensionX: u32,
         dimensionY: u32,
     ) -> Result<BitMatrix> {
-        let sampler = DefaultGridSampler::default();
+        let sampler = GridSamplerProvider::get_grid_sampler();
 
         let dst = Quadrilateral::new(
             point(0.5, 0.5),
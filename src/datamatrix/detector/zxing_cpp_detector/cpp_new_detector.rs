@@ -14,7 +14,7 @@ macro_rules! CHECK {
 use std::{cell::RefCell, rc::Rc};
 
 use crate::{
-    common::{BitMatrix, DefaultGridSampler, GridSampler, Quadrilateral, Result},
+    common::{BitMatrix, GridSamplerProvider, Quadrilateral, Result},
     datamatrix::detector::{
         zxing_cpp_detector::{util::intersect, BitMatrixCursor, RegressionLine},
         DatamatrixDetectorResult,
@@ -211,7 +211,7 @@ fn Scan(
             movedTowardsBy(bl, tl, br, 0.5),
         );
 
-        let grid_sampler = DefaultGridSampler::default();
+        let grid_sampler = GridSamplerProvider::get_grid_sampler();
         // let transform = PerspectiveTransform::quadrilateralToQuadrilateral(x0, y0, x1, y1, x2, y2, x3, y3, x0p, y0p, x1p, y1p, x2p, y2p, x3p, y3p);
         let dst = Quadrilateral::new(
             point(0.0, 0.0),
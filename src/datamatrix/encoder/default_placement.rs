@@ -15,6 +15,7 @@
  */
 
 use crate::common::Result;
+use crate::datamatrix::decoder::Version;
 use crate::Exceptions;
 
 const EMPTY_BIT_VAL: u8 = 13;
@@ -75,40 +76,114 @@ impl DefaultPlacement {
     }
 
     pub fn place(&mut self) -> Result<()> {
-        let mut pos = 0;
+        let codewords = self.codewords.chars().collect::<Vec<_>>();
+        let order = datamatrix_placement_order(self.numrows as u32, self.numcols as u32)?;
+        for (i, (col, row)) in order.into_iter().enumerate() {
+            // Note the conversion:
+            let v = *codewords
+                .get(i / 8)
+                .ok_or(Exceptions::INDEX_OUT_OF_BOUNDS)? as u32;
+            self.setBit(col as usize, row as usize, v & (1 << (7 - i % 8)) != 0);
+        }
+
+        // Lastly, if the lower right-hand corner is untouched, fill in fixed pattern
+        if self.noBit(self.numcols - 1, self.numrows - 1) {
+            self.setBit(self.numcols - 1, self.numrows - 1, true);
+            self.setBit(self.numcols - 2, self.numrows - 2, true);
+        }
+        Ok(())
+    }
+
+    #[allow(dead_code)]
+    fn toBitFieldStringArray(&self) -> Vec<String> {
+        let bits = self.getBits();
+        let numrows = self.getNumrows();
+        let numcols = self.getNumcols();
+        let mut array = Vec::with_capacity(numrows); //;[numrows];
+        let mut startpos = 0;
+        for _row in 0..numrows {
+            // for (int row = 0; row < numrows; row++) {
+            let mut sb = String::with_capacity(bits.len());
+            for i in 0..numcols {
+                // for (int i = 0; i < numcols; i++) {
+                sb.push(if bits[startpos + i] == 1 { '1' } else { '0' });
+            }
+            //array[row] = sb.toString();
+            array.push(sb);
+            startpos += numcols;
+        }
+
+        array
+    }
+}
+
+/**
+ * Lists the modules of an ECC200 data region in the order its codeword bits are placed, following
+ * Annex M.1 of ISO/IEC 16022:2000(E): each codeword in turn, from its most to its least
+ * significant bit, along the diagonal "utah" path and the special corner shapes.
+ *
+ * The two modules of the fixed pattern filling an otherwise untouched lower right-hand corner
+ * carry no data and are not included.
+ *
+ * @param num_rows the number of rows of the data region
+ * @param num_cols the number of columns of the data region
+ * @return the (x, y), i.e. (column, row), coordinate of each placed bit
+ * @throws IllegalArgumentException if no ECC200 symbol has a data region of this size
+ */
+pub fn datamatrix_placement_order(num_rows: u32, num_cols: u32) -> Result<Vec<(u32, u32)>> {
+    if !Version::isMappingMatrixSize(num_rows, num_cols) {
+        return Err(Exceptions::illegal_argument_with(format!(
+            "no data matrix symbol has a {num_rows}x{num_cols} data region"
+        )));
+    }
+    let mut walker = PlacementWalker {
+        numrows: num_rows as isize,
+        numcols: num_cols as isize,
+        visited: vec![false; (num_rows * num_cols) as usize],
+        order: Vec::with_capacity((num_rows * num_cols) as usize),
+    };
+    walker.walk();
+    Ok(walker.order)
+}
+
+struct PlacementWalker {
+    numrows: isize,
+    numcols: isize,
+    visited: Vec<bool>,
+    order: Vec<(u32, u32)>,
+}
+
+impl PlacementWalker {
+    fn noBit(&self, col: isize, row: isize) -> bool {
+        !self.visited[(row * self.numcols + col) as usize]
+    }
+
+    fn walk(&mut self) {
         let mut row = 4_isize;
         let mut col = 0_isize;
 
         loop {
             // repeatedly first check for one of the special corner cases, then...
-            if (row == self.numrows as isize) && (col == 0) {
-                self.corner1(pos)?;
-                pos += 1;
+            if (row == self.numrows) && (col == 0) {
+                self.corner1();
             }
-            if (row == self.numrows as isize - 2) && (col == 0) && ((self.numcols % 4) != 0) {
-                self.corner2(pos)?;
-                pos += 1;
+            if (row == self.numrows - 2) && (col == 0) && ((self.numcols % 4) != 0) {
+                self.corner2();
             }
-            if (row == self.numrows as isize - 2) && (col == 0) && (self.numcols % 8 == 4) {
-                self.corner3(pos)?;
-                pos += 1;
+            if (row == self.numrows - 2) && (col == 0) && (self.numcols % 8 == 4) {
+                self.corner3();
             }
-            if (row == self.numrows as isize + 4) && (col == 2) && ((self.numcols % 8) == 0) {
-                self.corner4(pos)?;
-                pos += 1;
+            if (row == self.numrows + 4) && (col == 2) && ((self.numcols % 8) == 0) {
+                self.corner4();
             }
             // sweep upward diagonally, inserting successive characters...
             loop {
-                if (row < self.numrows as isize)
-                    && (col >= 0)
-                    && self.noBit(col as usize, row as usize)
-                {
-                    self.utah(row, col, pos)?;
-                    pos += 1;
+                if (row < self.numrows) && (col >= 0) && self.noBit(col, row) {
+                    self.utah(row, col);
                 }
                 row -= 2;
                 col += 2;
-                if !(row >= 0 && (col < self.numcols as isize)) {
+                if !(row >= 0 && (col < self.numcols)) {
                     break;
                 }
             } //while (row >= 0 && (col < numcols));
@@ -117,17 +192,13 @@ impl DefaultPlacement {
 
             // and then sweep downward diagonally, inserting successive characters, ...
             loop {
-                if (row >= 0)
-                    && (col < self.numcols as isize)
-                    && self.noBit(col as usize, row as usize)
-                {
-                    self.utah(row, col, pos)?;
-                    pos += 1;
+                if (row >= 0) && (col < self.numcols) && self.noBit(col, row) {
+                    self.utah(row, col);
                 }
                 row += 2;
                 col -= 2;
 
-                if !((row < self.numrows as isize) && (col >= 0)) {
+                if !((row < self.numrows) && (col >= 0)) {
                     break;
                 }
             } //while ((row < numrows) && (col >= 0));
@@ -135,41 +206,30 @@ impl DefaultPlacement {
             col += 1;
 
             // ...until the entire array is scanned
-            if !((row < self.numrows as isize) || (col < self.numcols as isize)) {
+            if !((row < self.numrows) || (col < self.numcols)) {
                 break;
             }
         } // while ((row < numrows) || (col < numcols));
-
-        // Lastly, if the lower right-hand corner is untouched, fill in fixed pattern
-        if self.noBit(self.numcols - 1, self.numrows - 1) {
-            self.setBit(self.numcols - 1, self.numrows - 1, true);
-            self.setBit(self.numcols - 2, self.numrows - 2, true);
-        }
-        Ok(())
     }
 
-    fn module(&mut self, row: isize, col: isize, pos: usize, bit: u32) -> Result<()> {
+    fn module(&mut self, row: isize, col: isize) {
         let mut row = row;
         let mut col = col;
 
         if row < 0 {
-            row += self.numrows as isize;
-            col += 4 - ((self.numrows + 4) % 8) as isize;
+            row += self.numrows;
+            col += 4 - ((self.numrows + 4) % 8);
         }
         if col < 0 {
-            col += self.numcols as isize;
-            row += 4 - ((self.numcols + 4) % 8) as isize;
+            col += self.numcols;
+            row += 4 - ((self.numcols + 4) % 8);
         }
-        // Note the conversion:
-        let mut v = self
-            .codewords
-            .chars()
-            .nth(pos)
-            .ok_or(Exceptions::INDEX_OUT_OF_BOUNDS)? as u32;
-        v &= 1 << (8 - bit);
-        self.setBit(col as usize, row as usize, v != 0);
-
-        Ok(())
+        // some DMRE sizes wrap the column adjustment past the bottom edge
+        if row >= self.numrows {
+            row -= self.numrows;
+        }
+        self.visited[(row * self.numcols + col) as usize] = true;
+        self.order.push((col as u32, row as u32));
     }
 
     /**
@@ -177,88 +237,60 @@ impl DefaultPlacement {
      *
      * @param row the row
      * @param col the column
-     * @param pos character position
      */
-    fn utah(&mut self, row: isize, col: isize, pos: usize) -> Result<()> {
-        self.module(row - 2, col - 2, pos, 1)?;
-        self.module(row - 2, col - 1, pos, 2)?;
-        self.module(row - 1, col - 2, pos, 3)?;
-        self.module(row - 1, col - 1, pos, 4)?;
-        self.module(row - 1, col, pos, 5)?;
-        self.module(row, col - 2, pos, 6)?;
-        self.module(row, col - 1, pos, 7)?;
-        self.module(row, col, pos, 8)?;
-        Ok(())
+    fn utah(&mut self, row: isize, col: isize) {
+        self.module(row - 2, col - 2);
+        self.module(row - 2, col - 1);
+        self.module(row - 1, col - 2);
+        self.module(row - 1, col - 1);
+        self.module(row - 1, col);
+        self.module(row, col - 2);
+        self.module(row, col - 1);
+        self.module(row, col);
     }
 
-    fn corner1(&mut self, pos: usize) -> Result<()> {
-        self.module(self.numrows as isize - 1, 0, pos, 1)?;
-        self.module(self.numrows as isize - 1, 1, pos, 2)?;
-        self.module(self.numrows as isize - 1, 2, pos, 3)?;
-        self.module(0, self.numcols as isize - 2, pos, 4)?;
-        self.module(0, self.numcols as isize - 1, pos, 5)?;
-        self.module(1, self.numcols as isize - 1, pos, 6)?;
-        self.module(2, self.numcols as isize - 1, pos, 7)?;
-        self.module(3, self.numcols as isize - 1, pos, 8)?;
-        Ok(())
+    fn corner1(&mut self) {
+        self.module(self.numrows - 1, 0);
+        self.module(self.numrows - 1, 1);
+        self.module(self.numrows - 1, 2);
+        self.module(0, self.numcols - 2);
+        self.module(0, self.numcols - 1);
+        self.module(1, self.numcols - 1);
+        self.module(2, self.numcols - 1);
+        self.module(3, self.numcols - 1);
     }
 
-    fn corner2(&mut self, pos: usize) -> Result<()> {
-        self.module(self.numrows as isize - 3, 0, pos, 1)?;
-        self.module(self.numrows as isize - 2, 0, pos, 2)?;
-        self.module(self.numrows as isize - 1, 0, pos, 3)?;
-        self.module(0, self.numcols as isize - 4, pos, 4)?;
-        self.module(0, self.numcols as isize - 3, pos, 5)?;
-        self.module(0, self.numcols as isize - 2, pos, 6)?;
-        self.module(0, self.numcols as isize - 1, pos, 7)?;
-        self.module(1, self.numcols as isize - 1, pos, 8)?;
-        Ok(())
+    fn corner2(&mut self) {
+        self.module(self.numrows - 3, 0);
+        self.module(self.numrows - 2, 0);
+        self.module(self.numrows - 1, 0);
+        self.module(0, self.numcols - 4);
+        self.module(0, self.numcols - 3);
+        self.module(0, self.numcols - 2);
+        self.module(0, self.numcols - 1);
+        self.module(1, self.numcols - 1);
     }
 
-    fn corner3(&mut self, pos: usize) -> Result<()> {
-        self.module(self.numrows as isize - 3, 0, pos, 1)?;
-        self.module(self.numrows as isize - 2, 0, pos, 2)?;
-        self.module(self.numrows as isize - 1, 0, pos, 3)?;
-        self.module(0, self.numcols as isize - 2, pos, 4)?;
-        self.module(0, self.numcols as isize - 1, pos, 5)?;
-        self.module(1, self.numcols as isize - 1, pos, 6)?;
-        self.module(2, self.numcols as isize - 1, pos, 7)?;
-        self.module(3, self.numcols as isize - 1, pos, 8)?;
-        Ok(())
+    fn corner3(&mut self) {
+        self.module(self.numrows - 3, 0);
+        self.module(self.numrows - 2, 0);
+        self.module(self.numrows - 1, 0);
+        self.module(0, self.numcols - 2);
+        self.module(0, self.numcols - 1);
+        self.module(1, self.numcols - 1);
+        self.module(2, self.numcols - 1);
+        self.module(3, self.numcols - 1);
     }
 
-    fn corner4(&mut self, pos: usize) -> Result<()> {
-        self.module(self.numrows as isize - 1, 0, pos, 1)?;
-        self.module(self.numrows as isize - 1, self.numcols as isize - 1, pos, 2)?;
-        self.module(0, self.numcols as isize - 3, pos, 3)?;
-        self.module(0, self.numcols as isize - 2, pos, 4)?;
-        self.module(0, self.numcols as isize - 1, pos, 5)?;
-        self.module(1, self.numcols as isize - 3, pos, 6)?;
-        self.module(1, self.numcols as isize - 2, pos, 7)?;
-        self.module(1, self.numcols as isize - 1, pos, 8)?;
-        Ok(())
-    }
-
-    #[allow(dead_code)]
-    fn toBitFieldStringArray(&self) -> Vec<String> {
-        let bits = self.getBits();
-        let numrows = self.getNumrows();
-        let numcols = self.getNumcols();
-        let mut array = Vec::with_capacity(numrows); //;[numrows];
-        let mut startpos = 0;
-        for _row in 0..numrows {
-            // for (int row = 0; row < numrows; row++) {
-            let mut sb = String::with_capacity(bits.len());
-            for i in 0..numcols {
-                // for (int i = 0; i < numcols; i++) {
-                sb.push(if bits[startpos + i] == 1 { '1' } else { '0' });
-            }
-            //array[row] = sb.toString();
-            array.push(sb);
-            startpos += numcols;
-        }
-
-        array
+    fn corner4(&mut self) {
+        self.module(self.numrows - 1, 0);
+        self.module(self.numrows - 1, self.numcols - 1);
+        self.module(0, self.numcols - 3);
+        self.module(0, self.numcols - 2);
+        self.module(0, self.numcols - 1);
+        self.module(1, self.numcols - 3);
+        self.module(1, self.numcols - 2);
+        self.module(1, self.numcols - 1);
     }
 }
 
@@ -266,7 +298,7 @@ impl DefaultPlacement {
 mod test_placement {
     //private static final Pattern SPACE = Pattern.compile(" ");
 
-    use super::DefaultPlacement;
+    use super::{datamatrix_placement_order, DefaultPlacement};
 
     #[test]
     fn testPlacement() {
@@ -294,6 +326,44 @@ mod test_placement {
         }
     }
 
+    #[test]
+    fn testPlacementOrder() {
+        let order = datamatrix_placement_order(10, 10).expect("10x10 is a valid data region");
+        // 12 codewords of 8 bits; the fixed pattern corner carries no data
+        assert_eq!(96, order.len());
+        let expected = [
+            // codeword 1 wraps around to the right edge
+            (8, 0),
+            (9, 0),
+            (8, 1),
+            (9, 1),
+            (0, 3),
+            (8, 2),
+            (9, 2),
+            (0, 4),
+            // codeword 2 is a whole utah shape in the top left corner
+            (0, 0),
+            (1, 0),
+            (0, 1),
+            (1, 1),
+            (2, 1),
+            (0, 2),
+            (1, 2),
+            (2, 2),
+        ];
+        assert_eq!(expected.as_slice(), &order[..expected.len()]);
+    }
+
+    #[test]
+    fn testPlacementOrderInvalidSize() {
+        for (rows, cols) in [(0, 0), (1, 5), (6, 8), (8, 6), (10, 12)] {
+            assert!(
+                datamatrix_placement_order(rows, cols).is_err(),
+                "{rows}x{cols} is not a data region size"
+            );
+        }
+    }
+
     fn unvisualize(visualized: &str) -> String {
         let mut sb = String::new();
         for token in visualized.split(' ') {
@@ -16,9 +16,11 @@
 
 //package com.google.zxing;
 
+use std::cell::Cell;
 use std::collections::HashSet;
+use std::time::{Duration, Instant};
 
-use crate::{BarcodeFormat, PointCallback};
+use crate::{BarcodeFormat, DecodingHintDictionary, Exceptions, PointCallback};
 
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
@@ -109,6 +111,14 @@ pub enum DecodeHintType {
      */
     ALSO_INVERTED,
 
+    /**
+     * Upper bound, in milliseconds, on how long a decode attempt may take. Maps to a {@code u64}.
+     * This is best-effort: the budget is checked between readers and between scanned rows,
+     * a reader that is already running is never interrupted. When the budget is exceeded a
+     * {@link NotFoundException} is returned.
+     */
+    TIME_BUDGET_MS,
+
     /**
      * Specifies that the codes are expected to be in conformance with the specification
      * ISO/IEC 18004 regading the interpretation of character encoding. Values encoded in BYTE mode
@@ -219,6 +229,14 @@ pub enum DecodeHintValue {
      */
     AlsoInverted(bool),
 
+    /**
+     * Upper bound, in milliseconds, on how long a decode attempt may take. Maps to a {@code u64}.
+     * This is best-effort: the budget is checked between readers and between scanned rows,
+     * a reader that is already running is never interrupted. When the budget is exceeded a
+     * {@link NotFoundException} is returned.
+     */
+    TimeBudgetMs(u64),
+
     /**
      * Specifies that the codes are expected to be in conformance with the specification
      * ISO/IEC 18004 regading the interpretation of character encoding. Values encoded in BYTE mode
@@ -232,3 +250,65 @@ pub enum DecodeHintValue {
     #[cfg(feature = "allow_forced_iso_ied_18004_compliance")]
     QrAssumeSpecConformInput(bool),
 }
+
+thread_local! {
+    static TIME_BUDGET_DEADLINE: Cell<Option<Instant>> = const { Cell::new(None) };
+}
+
+/// The [`DecodeHintType::TIME_BUDGET_MS`] budget of a decode, held for as long as the decode runs.
+pub(crate) struct TimeBudget {
+    deadline: Option<Instant>,
+    outermost: bool,
+}
+
+impl TimeBudget {
+    /// Returns the point in time at which the decode has to give up, if it has a budget.
+    pub(crate) fn deadline(&self) -> Option<Instant> {
+        self.deadline
+    }
+}
+
+impl Drop for TimeBudget {
+    fn drop(&mut self) {
+        if self.outermost {
+            TIME_BUDGET_DEADLINE.with(|deadline| deadline.set(None));
+        }
+    }
+}
+
+/// Starts the [`DecodeHintType::TIME_BUDGET_MS`] budget of `hints`, unless a decode on this thread
+/// already has one running. Readers call this on entry and keep the result until they return, so
+/// that the budget starts at the outermost reader and everything it runs shares its deadline:
+/// nested readers, retries such as the rotated or inverted image, and each quadrant of a
+/// [`crate::multi::ByQuadrantReader`].
+pub(crate) fn start_time_budget(hints: &DecodingHintDictionary) -> TimeBudget {
+    if let Some(deadline) = TIME_BUDGET_DEADLINE.with(Cell::get) {
+        return TimeBudget {
+            deadline: Some(deadline),
+            outermost: false,
+        };
+    }
+    let Some(DecodeHintValue::TimeBudgetMs(budget)) = hints.get(&DecodeHintType::TIME_BUDGET_MS)
+    else {
+        return TimeBudget {
+            deadline: None,
+            outermost: false,
+        };
+    };
+    let deadline = Instant::now() + Duration::from_millis(*budget);
+    TIME_BUDGET_DEADLINE.with(|cell| cell.set(Some(deadline)));
+    TimeBudget {
+        deadline: Some(deadline),
+        outermost: true,
+    }
+}
+
+/// Fails with a `NotFoundException` once `deadline` has passed.
+pub(crate) fn check_time_budget(deadline: Option<Instant>) -> Result<(), Exceptions> {
+    match deadline {
+        Some(deadline) if Instant::now() >= deadline => {
+            Err(Exceptions::not_found_with("decode time budget exceeded"))
+        }
+        _ => Ok(()),
+    }
+}
@@ -13,8 +13,8 @@ pub enum Exceptions {
     IllegalStateException(String),
     #[error("ArithmeticException{}", if .0.is_empty() { String::new()  } else { format!(" - {}", .0) })]
     ArithmeticException(String),
-    #[error("NotFoundException{}", if .0.is_empty() { String::new()  } else { format!(" - {}", .0) })]
-    NotFoundException(String),
+    #[error("NotFoundException{}{}", if *.0 == NotFoundReason::Generic { String::new() } else { format!(" ({:?})", .0) }, if .1.is_empty() { String::new() } else { format!(" - {}", .1) })]
+    NotFoundException(NotFoundReason, String),
     #[error("FormatException{}", if .0.is_empty() { String::new()  } else { format!(" - {}", .0) })]
     FormatException(String),
     #[error("ChecksumException{}", if .0.is_empty() { String::new()  } else { format!(" - {}", .0) })]
@@ -35,6 +35,23 @@ pub enum Exceptions {
     ReaderDecodeException(),
 }
 
+/// Why a `NotFoundException` was raised.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum NotFoundReason {
+    /// No usable finder pattern (or not enough of them) was located.
+    NoFinderPattern,
+    /// The image has too little contrast to pick a black point.
+    LowContrast,
+    /// A sampled point fell outside the image.
+    SamplingOutOfBounds,
+    /// The detected symbol dimensions are inconsistent or invalid.
+    DimensionMismatch,
+    /// No more specific reason is known.
+    #[default]
+    Generic,
+}
+
 impl Exceptions {
     pub const ILLEGAL_ARGUMENT: Self = Self::IllegalArgumentException(String::new());
     pub fn illegal_argument_with<I: Into<String>>(x: I) -> Self {
@@ -56,9 +73,12 @@ impl Exceptions {
         Self::ArithmeticException(x.into())
     }
 
-    pub const NOT_FOUND: Self = Self::NotFoundException(String::new());
+    pub const NOT_FOUND: Self = Self::NotFoundException(NotFoundReason::Generic, String::new());
     pub fn not_found_with<I: Into<String>>(x: I) -> Self {
-        Self::NotFoundException(x.into())
+        Self::NotFoundException(NotFoundReason::Generic, x.into())
+    }
+    pub fn not_found_because<I: Into<String>>(reason: NotFoundReason, x: I) -> Self {
+        Self::NotFoundException(reason, x.into())
     }
 
     pub const FORMAT: Self = Self::FormatException(String::new());
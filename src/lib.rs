@@ -6,11 +6,12 @@ pub mod client;
 pub mod common;
 mod exceptions;
 pub mod maxicode;
+pub mod prelude;
 pub mod qrcode;
 
 use std::{collections::HashMap, rc::Rc};
 
-pub use exceptions::Exceptions;
+pub use exceptions::{Exceptions, NotFoundReason};
 
 #[cfg(feature = "image")]
 mod buffered_image_luminance_source;
@@ -24,6 +25,9 @@ mod PlanarYUVLuminanceSourceTestCase;
 #[cfg(test)]
 mod rgb_luminance_source_test_case;
 
+#[cfg(test)]
+mod result_point_utils_test_case;
+
 pub type EncodingHintDictionary = HashMap<EncodeHintType, EncodeHintValue>;
 pub type DecodingHintDictionary = HashMap<DecodeHintType, DecodeHintValue>;
 pub type MetadataDictionary = HashMap<RXingResultMetadataType, RXingResultMetadataValue>;
@@ -2,9 +2,7 @@
 use num::integer::Roots;
 
 use crate::{
-    common::{
-        BitMatrix, DefaultGridSampler, DetectorRXingResult, GridSampler, Quadrilateral, Result,
-    },
+    common::{BitMatrix, DetectorRXingResult, GridSamplerProvider, Quadrilateral, Result},
     point, Exceptions, Point,
 };
 
@@ -338,7 +336,7 @@ pub fn detect(image: &BitMatrix, try_harder: bool) -> Result<MaxicodeDetectionRe
                 return Err(Exceptions::NOT_FOUND)
             }
         };
-        let grid_sampler = DefaultGridSampler::default();
+        let grid_sampler = GridSamplerProvider::get_grid_sampler();
 
         let [tl, bl, tr, br] = symbol_box.0;
 
@@ -1032,12 +1030,12 @@ fn compare_circle(a: &Circle, b: &Circle) -> std::cmp::Ordering {
 
 /// Read appropriate bits from a bitmatrix for the maxicode decoder
 pub fn read_bits(image: &BitMatrix) -> Result<BitMatrix> {
-    let enclosingRectangle = image.getEnclosingRectangle().ok_or(Exceptions::NOT_FOUND)?;
+    let enclosingRectangle = image.enclosing_rectangle().ok_or(Exceptions::NOT_FOUND)?;
 
-    let left = enclosingRectangle[0];
-    let top = enclosingRectangle[1];
-    let width = enclosingRectangle[2];
-    let height = enclosingRectangle[3];
+    let left = enclosingRectangle.left;
+    let top = enclosingRectangle.top;
+    let width = enclosingRectangle.width;
+    let height = enclosingRectangle.height;
 
     // Now just read off the bits
     let mut bits = BitMatrix::new(MaxiCodeReader::MATRIX_WIDTH, MaxiCodeReader::MATRIX_HEIGHT)?;
@@ -124,17 +124,12 @@ impl MaxiCodeReader {
      * case.
      */
     fn extractPureBits(image: &BitMatrix) -> Result<BitMatrix> {
-        let enclosingRectangleOption = image.getEnclosingRectangle();
-        if enclosingRectangleOption.is_none() {
-            return Err(Exceptions::NOT_FOUND);
-        }
-
-        let enclosingRectangle = enclosingRectangleOption.ok_or(Exceptions::NOT_FOUND)?;
+        let enclosingRectangle = image.enclosing_rectangle().ok_or(Exceptions::NOT_FOUND)?;
 
-        let left = enclosingRectangle[0];
-        let top = enclosingRectangle[1];
-        let width = enclosingRectangle[2];
-        let height = enclosingRectangle[3];
+        let left = enclosingRectangle.left;
+        let top = enclosingRectangle.top;
+        let width = enclosingRectangle.width;
+        let height = enclosingRectangle.height;
 
         // Now just read off the bits
         let mut bits = BitMatrix::new(Self::MATRIX_WIDTH, Self::MATRIX_HEIGHT)?;
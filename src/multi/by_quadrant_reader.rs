@@ -17,6 +17,7 @@
 use std::collections::HashMap;
 
 use crate::common::Result;
+use crate::decode_hints::start_time_budget;
 use crate::{point, Binarizer, Exceptions, Point, RXingResult, Reader};
 
 /**
@@ -39,6 +40,7 @@ impl<T: Reader> Reader for ByQuadrantReader<T> {
         image: &mut crate::BinaryBitmap<B>,
         hints: &crate::DecodingHintDictionary,
     ) -> Result<crate::RXingResult> {
+        let _time_budget = start_time_budget(hints);
         let width = image.get_width();
         let height = image.get_height();
         let halfWidth = width / 2;
@@ -50,7 +52,7 @@ impl<T: Reader> Reader for ByQuadrantReader<T> {
         // No need to call makeAbsolute as results will be relative to original top left here
         // This is a match because only NotFoundExceptions should be ignored
         match attempt {
-            Err(Exceptions::NotFoundException(_)) => {}
+            Err(Exceptions::NotFoundException(..)) => {}
             _ => return attempt,
         }
 
@@ -64,7 +66,7 @@ impl<T: Reader> Reader for ByQuadrantReader<T> {
                 let points = Self::makeAbsolute(res.getPoints(), halfWidth as f32, 0.0);
                 return Ok(RXingResult::new_from_existing_result(res, points));
             }
-            Err(Exceptions::NotFoundException(_)) => {}
+            Err(Exceptions::NotFoundException(..)) => {}
             _ => return result,
         }
 
@@ -77,7 +79,7 @@ impl<T: Reader> Reader for ByQuadrantReader<T> {
                 let points = Self::makeAbsolute(res.getPoints(), 0.0, halfHeight as f32);
                 return Ok(RXingResult::new_from_existing_result(res, points));
             }
-            Err(Exceptions::NotFoundException(_)) => {}
+            Err(Exceptions::NotFoundException(..)) => {}
             _ => return result,
         }
 
@@ -92,7 +94,7 @@ impl<T: Reader> Reader for ByQuadrantReader<T> {
                     Self::makeAbsolute(res.getPoints(), halfWidth as f32, halfHeight as f32);
                 return Ok(RXingResult::new_from_existing_result(res, points));
             }
-            Err(Exceptions::NotFoundException(_)) => {}
+            Err(Exceptions::NotFoundException(..)) => {}
             _ => return result,
         }
 
@@ -11,3 +11,9 @@ pub use generic_multiple_barcode_reader::*;
 #[cfg(test)]
 #[cfg(feature = "image")]
 mod multi_test_case;
+
+mod structured_append_assembler;
+pub use structured_append_assembler::*;
+
+#[cfg(test)]
+mod structured_append_assembler_test_case;
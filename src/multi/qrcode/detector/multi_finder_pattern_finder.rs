@@ -19,7 +19,8 @@ use std::cmp::Ordering;
 use crate::{
     common::{BitMatrix, Result},
     qrcode::detector::{FinderPattern, FinderPatternFinder, FinderPatternInfo},
-    result_point_utils, DecodeHintType, DecodingHintDictionary, Exceptions, Point, PointCallback,
+    result_point_utils, DecodeHintType, DecodingHintDictionary, Exceptions, NotFoundReason, Point,
+    PointCallback,
 };
 
 // max. legal count of modules per QR code edge (177)
@@ -92,7 +93,8 @@ impl<'a> MultiFinderPatternFinder<'_> {
 
         if size < 3 {
             // Couldn't find enough finder patterns
-            return Err(Exceptions::not_found_with(
+            return Err(Exceptions::not_found_because(
+                NotFoundReason::NoFinderPattern,
                 "Couldn't find enough finder patterns",
             ));
         }
@@ -212,7 +214,10 @@ impl<'a> MultiFinderPatternFinder<'_> {
         if !results.is_empty() {
             Ok(results)
         } else {
-            Err(Exceptions::NOT_FOUND)
+            Err(Exceptions::not_found_because(
+                NotFoundReason::NoFinderPattern,
+                "no finder pattern triple fits",
+            ))
         }
     }
 
@@ -0,0 +1,111 @@
+use std::io::Write;
+
+use crate::{
+    common::Result, Exceptions, RXingResult, RXingResultMetadataType, RXingResultMetadataValue,
+};
+
+/**
+ * Collects the symbols of a structured append sequence and reassembles their text.
+ *
+ * Each part must carry `STRUCTURED_APPEND_SEQUENCE` metadata, encoded as in QR Code: the part's
+ * position in the high nibble and the total number of parts minus one in the low nibble.
+ */
+#[derive(Default)]
+pub struct StructuredAppendAssembler {
+    parts: Vec<RXingResult>,
+}
+
+impl StructuredAppendAssembler {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a decoded part, failing if it carries no structured append sequence.
+    pub fn add(&mut self, result: RXingResult) -> Result<()> {
+        sequence_of(&result)?;
+        self.parts.push(result);
+        Ok(())
+    }
+
+    pub fn len(&self) -> usize {
+        self.parts.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.parts.is_empty()
+    }
+
+    /// Whether every part of the sequence has been added exactly once.
+    pub fn is_complete(&self) -> bool {
+        self.ordered_parts().is_ok()
+    }
+
+    /**
+     * Writes the text of every part, in sequence order, to `w`.
+     *
+     * Nothing is written unless the sequence is complete: all parts must agree on the total
+     * count, and each position must be present exactly once.
+     */
+    pub fn write_to<W: Write>(&self, w: &mut W) -> Result<()> {
+        for part in self.ordered_parts()? {
+            w.write_all(part.getText().as_bytes())
+                .map_err(|e| Exceptions::runtime_with(format!("couldn't write part: {e}")))?;
+        }
+        Ok(())
+    }
+
+    fn ordered_parts(&self) -> Result<Vec<&RXingResult>> {
+        let Some(first) = self.parts.first() else {
+            return Err(Exceptions::not_found_with("no structured append parts"));
+        };
+        let total = (sequence_of(first)? & 0x0F) as usize + 1;
+
+        let mut ordered: Vec<Option<&RXingResult>> = vec![None; total];
+        for part in &self.parts {
+            let sequence = sequence_of(part)?;
+            if (sequence & 0x0F) as usize + 1 != total {
+                return Err(Exceptions::format_with(
+                    "structured append parts disagree on the total count",
+                ));
+            }
+            let position = (sequence >> 4) as usize;
+            match ordered.get_mut(position) {
+                Some(slot @ None) => *slot = Some(part),
+                Some(Some(_)) => {
+                    return Err(Exceptions::format_with(format!(
+                        "duplicate structured append part {position}"
+                    )))
+                }
+                None => {
+                    return Err(Exceptions::format_with(format!(
+                        "structured append part {position} out of range for {total} parts"
+                    )))
+                }
+            }
+        }
+
+        ordered
+            .into_iter()
+            .enumerate()
+            .map(|(position, part)| {
+                part.ok_or_else(|| {
+                    Exceptions::not_found_with(format!("missing structured append part {position}"))
+                })
+            })
+            .collect()
+    }
+}
+
+fn sequence_of(result: &RXingResult) -> Result<i32> {
+    match result
+        .getRXingResultMetadata()
+        .get(&RXingResultMetadataType::STRUCTURED_APPEND_SEQUENCE)
+    {
+        Some(RXingResultMetadataValue::StructuredAppendSequence(sequence)) if *sequence >= 0 => {
+            Ok(*sequence)
+        }
+        _ => Err(Exceptions::illegal_argument_with(
+            "result has no structured append sequence",
+        )),
+    }
+}
@@ -0,0 +1,52 @@
+use std::io::Cursor;
+
+use crate::{
+    BarcodeFormat, Exceptions, RXingResult, RXingResultMetadataType, RXingResultMetadataValue,
+};
+
+use super::StructuredAppendAssembler;
+
+fn part(text: &str, position: i32, total: i32) -> RXingResult {
+    let mut result = RXingResult::new(text, Vec::new(), Vec::new(), BarcodeFormat::QR_CODE);
+    result.putMetadata(
+        RXingResultMetadataType::STRUCTURED_APPEND_SEQUENCE,
+        RXingResultMetadataValue::StructuredAppendSequence((position << 4) + total - 1),
+    );
+    result
+}
+
+#[test]
+fn test_write_to() {
+    let mut assembler = StructuredAppendAssembler::new();
+    assembler.add(part("SA3", 2, 3)).unwrap();
+    assembler.add(part("SA1", 0, 3)).unwrap();
+    assembler.add(part("SA2", 1, 3)).unwrap();
+    assert!(assembler.is_complete());
+
+    let mut cursor = Cursor::new(Vec::new());
+    assembler.write_to(&mut cursor).expect("must write");
+    assert_eq!(b"SA1SA2SA3".as_slice(), cursor.get_ref().as_slice());
+}
+
+#[test]
+fn test_write_to_missing_part() {
+    let mut assembler = StructuredAppendAssembler::new();
+    assembler.add(part("SA1", 0, 3)).unwrap();
+    assembler.add(part("SA3", 2, 3)).unwrap();
+    assert!(!assembler.is_complete());
+
+    let mut out = Vec::new();
+    assert!(matches!(
+        assembler.write_to(&mut out),
+        Err(Exceptions::NotFoundException(..))
+    ));
+    assert!(out.is_empty());
+}
+
+#[test]
+fn test_add_requires_sequence() {
+    let mut assembler = StructuredAppendAssembler::new();
+    let plain = RXingResult::new("NotSA", Vec::new(), Vec::new(), BarcodeFormat::QR_CODE);
+    assert!(assembler.add(plain).is_err());
+    assert!(assembler.is_empty());
+}
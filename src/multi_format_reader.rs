@@ -15,8 +15,10 @@
  */
 
 use std::collections::{HashMap, HashSet};
+use std::time::Instant;
 
 use crate::common::Result;
+use crate::decode_hints::{check_time_budget, start_time_budget};
 use crate::{
     aztec::AztecReader, datamatrix::DataMatrixReader, maxicode::MaxiCodeReader,
     oned::MultiFormatOneDReader, pdf417::PDF417Reader, qrcode::QRCodeReader, BarcodeFormat,
@@ -38,6 +40,7 @@ pub struct MultiFormatReader {
     possible_formats: HashSet<BarcodeFormat>,
     try_harder: bool,
     one_d_reader: MultiFormatOneDReader,
+    deadline: Option<Instant>,
 }
 
 impl Reader for MultiFormatReader {
@@ -125,10 +128,13 @@ impl MultiFormatReader {
         &mut self,
         image: &mut BinaryBitmap<B>,
     ) -> Result<RXingResult> {
+        let time_budget = start_time_budget(&self.hints);
+        self.deadline = time_budget.deadline();
         let res = self.decode_formats(image);
         if res.is_ok() {
             return res;
         }
+        check_time_budget(self.deadline)?;
         if matches!(
             self.hints.get(&DecodeHintType::ALSO_INVERTED),
             Some(DecodeHintValue::AlsoInverted(true))
@@ -139,6 +145,7 @@ impl MultiFormatReader {
             if res.is_ok() {
                 return res;
             }
+            check_time_budget(self.deadline)?;
         }
         Err(Exceptions::NOT_FOUND)
     }
@@ -157,11 +164,13 @@ impl MultiFormatReader {
                 || self.possible_formats.contains(&BarcodeFormat::RSS_14)
                 || self.possible_formats.contains(&BarcodeFormat::RSS_EXPANDED);
             if one_d && !self.try_harder {
+                check_time_budget(self.deadline)?;
                 if let Ok(res) = self.one_d_reader.decode_with_hints(image, &self.hints) {
                     return Ok(res);
                 }
             }
             for possible_format in self.possible_formats.iter() {
+                check_time_budget(self.deadline)?;
                 let res = match possible_format {
                     BarcodeFormat::QR_CODE => {
                         QRCodeReader::default().decode_with_hints(image, &self.hints)
@@ -185,34 +194,42 @@ impl MultiFormatReader {
                 }
             }
             if one_d && self.try_harder {
+                check_time_budget(self.deadline)?;
                 if let Ok(res) = self.one_d_reader.decode_with_hints(image, &self.hints) {
                     return Ok(res);
                 }
             }
         } else {
             if !self.try_harder {
+                check_time_budget(self.deadline)?;
                 if let Ok(res) = self.one_d_reader.decode_with_hints(image, &self.hints) {
                     return Ok(res);
                 }
             }
 
+            check_time_budget(self.deadline)?;
             if let Ok(res) = QRCodeReader::default().decode_with_hints(image, &self.hints) {
                 return Ok(res);
             }
+            check_time_budget(self.deadline)?;
             if let Ok(res) = DataMatrixReader::default().decode_with_hints(image, &self.hints) {
                 return Ok(res);
             }
+            check_time_budget(self.deadline)?;
             if let Ok(res) = AztecReader::default().decode_with_hints(image, &self.hints) {
                 return Ok(res);
             }
+            check_time_budget(self.deadline)?;
             if let Ok(res) = PDF417Reader::default().decode_with_hints(image, &self.hints) {
                 return Ok(res);
             }
+            check_time_budget(self.deadline)?;
             if let Ok(res) = MaxiCodeReader::default().decode_with_hints(image, &self.hints) {
                 return Ok(res);
             }
 
             if self.try_harder {
+                check_time_budget(self.deadline)?;
                 if let Ok(res) = self.one_d_reader.decode_with_hints(image, &self.hints) {
                     return Ok(res);
                 }
@@ -222,3 +239,102 @@ impl MultiFormatReader {
         Err(Exceptions::UNSUPPORTED_OPERATION)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use rand::Rng;
+
+    use crate::{
+        common::HybridBinarizer, multi::ByQuadrantReader, oned::EAN13Reader, BinaryBitmap,
+        DecodeHintType, DecodeHintValue, Exceptions, Luma8LuminanceSource, MultiFormatReader,
+        Reader,
+    };
+
+    fn noise_image() -> BinaryBitmap<HybridBinarizer<Luma8LuminanceSource>> {
+        let mut r = rand::thread_rng();
+        let (width, height) = (400, 400);
+        let luma = (0..width * height).map(|_| r.gen()).collect();
+        BinaryBitmap::new(HybridBinarizer::new(Luma8LuminanceSource::new(
+            luma, width, height,
+        )))
+    }
+
+    fn budget_hints(budget: u64) -> HashMap<DecodeHintType, DecodeHintValue> {
+        HashMap::from([
+            (DecodeHintType::TRY_HARDER, DecodeHintValue::TryHarder(true)),
+            (
+                DecodeHintType::TIME_BUDGET_MS,
+                DecodeHintValue::TimeBudgetMs(budget),
+            ),
+        ])
+    }
+
+    #[test]
+    fn test_time_budget_exceeded() {
+        let expected = || Exceptions::not_found_with("decode time budget exceeded");
+
+        let mut image = noise_image();
+        let result = MultiFormatReader::default().decode_with_hints(&mut image, &budget_hints(0));
+        assert_eq!(Some(expected()), result.err());
+
+        let result = EAN13Reader.decode_with_hints(&mut image, &budget_hints(0));
+        assert_eq!(Some(expected()), result.err());
+
+        // the budget ends with the decode it was set for
+        let result = EAN13Reader.decode(&mut image);
+        assert_eq!(Some(Exceptions::NOT_FOUND), result.err());
+    }
+
+    mod slow_reader {
+        use std::{thread, time::Duration};
+
+        use rxing_one_d_proc_derive::OneDReader;
+
+        use crate::{common::BitArray, oned::OneDReader, Exceptions, RXingResult};
+
+        /// Takes a millisecond for every row it is handed and never finds anything.
+        #[derive(OneDReader)]
+        pub struct SlowReader;
+
+        impl OneDReader for SlowReader {
+            fn decode_row(
+                &mut self,
+                _rowNumber: u32,
+                _row: &BitArray,
+                _hints: &DecodingHintDictionary,
+            ) -> Result<RXingResult, Exceptions> {
+                thread::sleep(Duration::from_millis(1));
+                Err(Exceptions::NOT_FOUND)
+            }
+        }
+    }
+
+    #[test]
+    fn test_time_budget_shared_by_nested_readers() {
+        // each of the five quadrants scans 15 rows twice, so takes at least 30ms on its own, and
+        // twice that once the rotated retry is included. Only a budget shared by all of them runs
+        // out before the last quadrant.
+        let mut r = rand::thread_rng();
+        let luma = (0..32 * 32).map(|_| r.gen()).collect();
+        let mut image = BinaryBitmap::new(HybridBinarizer::new(Luma8LuminanceSource::new(
+            luma, 32, 32,
+        )));
+
+        let result = ByQuadrantReader::new(slow_reader::SlowReader)
+            .decode_with_hints(&mut image, &budget_hints(50));
+        assert_eq!(
+            Some(Exceptions::not_found_with("decode time budget exceeded")),
+            result.err()
+        );
+    }
+
+    #[test]
+    fn test_time_budget_not_exceeded() {
+        let mut image = noise_image();
+        let result = MultiFormatReader::default()
+            .decode_with_hints(&mut image, &budget_hints(u32::MAX as u64));
+        assert_eq!(Some(Exceptions::NOT_FOUND), result.err());
+    }
+}
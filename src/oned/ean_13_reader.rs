@@ -156,3 +156,45 @@ impl EAN13Reader {
         Err(Exceptions::NOT_FOUND)
     }
 }
+
+#[cfg(test)]
+mod EAN13ReaderTestCase {
+    use std::collections::HashMap;
+
+    use crate::{
+        oned::{OneDReader, UPCAReader},
+        BarcodeFormat, Writer,
+    };
+
+    use super::super::EAN13Writer;
+    use super::EAN13Reader;
+
+    #[test]
+    fn testRawBytes() {
+        let matrix = EAN13Writer
+            .encode("5901234123457", &BarcodeFormat::EAN_13, 0, 0)
+            .expect("encode");
+        let result = EAN13Reader
+            .decode_row(0, &matrix.getRow(0), &HashMap::new())
+            .expect("decode row");
+        assert_eq!("5901234123457", result.getText());
+        assert_eq!(
+            &vec![5, 9, 0, 1, 2, 3, 4, 1, 2, 3, 4, 5, 7],
+            result.getRawBytes()
+        );
+        assert_eq!(13 * 8, result.getNumBits());
+
+        // the leading zero of an EAN-13 read as UPC-A is dropped from its raw bytes too
+        let matrix = EAN13Writer
+            .encode("0123456789012", &BarcodeFormat::EAN_13, 0, 0)
+            .expect("encode");
+        let result = UPCAReader::default()
+            .decode_row(0, &matrix.getRow(0), &HashMap::new())
+            .expect("decode row");
+        assert_eq!("123456789012", result.getText());
+        assert_eq!(
+            &vec![1, 2, 3, 4, 5, 6, 7, 8, 9, 0, 1, 2],
+            result.getRawBytes()
+        );
+    }
+}
@@ -24,6 +24,7 @@ use super::ITFReader;
 use super::MultiFormatUPCEANReader;
 use super::OneDReader;
 use crate::common::Result;
+use crate::decode_hints::start_time_budget;
 use crate::DecodeHintValue;
 use crate::Exceptions;
 use crate::{BarcodeFormat, Binarizer, RXingResult};
@@ -180,6 +181,7 @@ impl Reader for MultiFormatOneDReader {
         image: &mut crate::BinaryBitmap<B>,
         hints: &DecodingHintDictionary,
     ) -> Result<RXingResult> {
+        let _time_budget = start_time_budget(hints);
         let first_try = self._do_decode(image, hints);
         if first_try.is_ok() {
             return first_try;
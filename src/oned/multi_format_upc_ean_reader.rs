@@ -15,6 +15,7 @@
  */
 
 use crate::common::Result;
+use crate::decode_hints::start_time_budget;
 use crate::DecodeHintValue;
 use crate::Exceptions;
 use crate::RXingResult;
@@ -179,7 +180,7 @@ impl MultiFormatUPCEANReader {
             // Transfer the metadata across
             let mut resultUPCA = RXingResult::new(
                 &result.getText()[1..],
-                result.getRawBytes().clone(),
+                result.getRawBytes().get(1..).unwrap_or_default().to_vec(),
                 result.getPoints().clone(),
                 BarcodeFormat::UPC_A,
             );
@@ -209,6 +210,7 @@ impl Reader for MultiFormatUPCEANReader {
         image: &mut crate::BinaryBitmap<B>,
         hints: &DecodingHintDictionary,
     ) -> Result<RXingResult> {
+        let _time_budget = start_time_budget(hints);
         let first_try = self._do_decode(image, hints);
         if first_try.is_ok() {
             return first_try;
@@ -16,6 +16,7 @@
 
 use crate::{
     common::{BitArray, Result},
+    decode_hints::{check_time_budget, start_time_budget},
     point, Binarizer, BinaryBitmap, DecodeHintType, DecodeHintValue, DecodingHintDictionary,
     Exceptions, RXingResult, RXingResultMetadataType, RXingResultMetadataValue, Reader,
 };
@@ -48,6 +49,8 @@ pub trait OneDReader: Reader {
         hints: &DecodingHintDictionary,
     ) -> Result<RXingResult> {
         let mut hints = hints.clone();
+        let time_budget = start_time_budget(&hints);
+        let deadline = time_budget.deadline();
         let width = image.get_width();
         let height = image.get_height();
 
@@ -78,6 +81,7 @@ pub trait OneDReader: Reader {
                 // Oops, if we run off the top or bottom, stop
                 break;
             }
+            check_time_budget(deadline)?;
 
             // Estimate black point for this row and load it:
             let mut row = if let Ok(res) = image.get_black_row(row_number as usize) {
@@ -28,6 +28,7 @@ use std::collections::HashMap;
 
 use crate::{
     common::{BitArray, Result},
+    decode_hints::start_time_budget,
     oned::{
         record_pattern, record_pattern_in_reverse,
         rss::{
@@ -183,6 +184,7 @@ impl Reader for RSSExpandedReader {
         image: &mut crate::BinaryBitmap<B>,
         hints: &DecodingHintDictionary,
     ) -> Result<crate::RXingResult> {
+        let _time_budget = start_time_budget(hints);
         if let Ok(res) = self._do_decode(image, hints) {
             Ok(res)
         } else {
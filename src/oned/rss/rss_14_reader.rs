@@ -18,6 +18,7 @@ use std::collections::HashMap;
 
 use crate::{
     common::{BitArray, Result},
+    decode_hints::start_time_budget,
     oned::{one_d_reader, OneDReader},
     point, BarcodeFormat, Binarizer, DecodeHintType, DecodeHintValue, DecodingHintDictionary,
     Exceptions, RXingResult, RXingResultMetadataType, RXingResultMetadataValue, Reader,
@@ -83,6 +84,7 @@ impl Reader for RSS14Reader {
         image: &mut crate::BinaryBitmap<B>,
         hints: &DecodingHintDictionary,
     ) -> Result<crate::RXingResult> {
+        let _time_budget = start_time_budget(hints);
         if let Ok(res) = self._do_decode(image, hints) {
             Ok(res)
         } else {
@@ -93,7 +93,7 @@ impl UPCAReader {
         if let Some(stripped_text) = text.strip_prefix('0') {
             let mut upcaRXingResult = RXingResult::new(
                 stripped_text,
-                Vec::new(),
+                result.getRawBytes().get(1..).unwrap_or_default().to_vec(),
                 result.getPoints().to_vec(),
                 BarcodeFormat::UPC_A,
             );
@@ -202,7 +202,11 @@ pub trait UPCEANReader: OneDReader {
         let format = self.getBarcodeFormat();
         let mut decodeRXingResult = RXingResult::new(
             &resultString,
-            Vec::new(), // no natural byte representation for these barcodes
+            // the raw data of these barcodes are their digits, one per byte
+            resultString
+                .chars()
+                .filter_map(|c| c.to_digit(10).map(|digit| digit as u8))
+                .collect(),
             vec![
                 point(left, rowNumber as f32),
                 point(right, rowNumber as f32),
@@ -159,7 +159,7 @@ pub fn decode(codewords: &[u32], ecLevel: &str) -> Result<DecoderRXingResult> {
         }
     }
 
-    result = result.build_result();
+    result = result.build_result()?;
 
     if result.is_empty() && resultMetadata.getFileId().is_empty() {
         return Err(Exceptions::FORMAT);
@@ -16,7 +16,7 @@
 
 use std::{fmt::Display, rc::Rc};
 
-use crate::pdf417::pdf_417_common;
+use crate::{common::Result, pdf417::pdf_417_common, Exceptions};
 
 use super::{
     BarcodeMetadata, BoundingBox, Codeword, DetectionRXingResultColumnTrait,
@@ -147,7 +147,7 @@ impl DetectionRXingResult {
 
     fn adjustRowNumbersFromBothRI(&mut self) {
         if self.detectionRXingResultColumns[0].is_none()
-            && self.detectionRXingResultColumns[self.barcodeColumnCount + 1].is_none()
+            || self.detectionRXingResultColumns[self.barcodeColumnCount + 1].is_none()
         {
             return;
         }
@@ -609,17 +609,24 @@ impl DetectionRXingResult {
         self.boundingBox.clone()
     }
 
+    /**
+     * @param barcodeColumn column index, 0 and getBarcodeColumnCount() + 1 being the row indicator columns
+     * @throws IllegalArgumentException if barcodeColumn is out of range
+     */
     pub fn setDetectionRXingResultColumn(
         &mut self,
         barcodeColumn: usize,
         detectionRXingResultColumn: Option<impl DetectionRXingResultRowIndicatorColumn + 'static>,
-    ) {
-        self.detectionRXingResultColumns[barcodeColumn] =
-            if let Some(detectionRXingResultColumn) = detectionRXingResultColumn {
-                Some(Box::new(detectionRXingResultColumn))
-            } else {
-                None
-            };
+    ) -> Result<()> {
+        let Some(slot) = self.detectionRXingResultColumns.get_mut(barcodeColumn) else {
+            return Err(Exceptions::illegal_argument_with(format!(
+                "barcode column {barcodeColumn} out of range for {} columns",
+                self.barcodeColumnCount
+            )));
+        };
+        *slot = detectionRXingResultColumn
+            .map(|column| Box::new(column) as Box<dyn DetectionRXingResultColumnTrait>);
+        Ok(())
     }
 
     pub fn getDetectionRXingResultColumn(
@@ -643,41 +650,29 @@ impl Display for DetectionRXingResult {
         if rowIndicatorColumn.is_none() {
             rowIndicatorColumn = &self.detectionRXingResultColumns[self.barcodeColumnCount + 1];
         }
-        // try (Formatter formatter = new Formatter()) {
-        for codewordsRow in 0..rowIndicatorColumn.as_ref().unwrap().getCodewords().len() {
-            //   for (int codewordsRow = 0; codewordsRow < rowIndicatorColumn.getCodewords().length; codewordsRow++) {
-            write!(f, "CW {codewordsRow:3}")?;
-            // formatter.format("CW %3d:", codewordsRow);
-            for barcodeColumn in 0..self.barcodeColumnCount + 2 {
-                // for (int barcodeColumn = 0; barcodeColumn < barcodeColumnCount + 2; barcodeColumn++) {
-                if self.detectionRXingResultColumns[barcodeColumn].is_none() {
-                    write!(f, "    |   ")?;
-                    // formatter.format("    |   ");
-                    continue;
-                }
-                let codeword = self.detectionRXingResultColumns[barcodeColumn]
+        // without a row indicator column there are no rows to print
+        let Some(rowIndicatorColumn) = rowIndicatorColumn else {
+            return Ok(());
+        };
+        for codewordsRow in 0..rowIndicatorColumn.getCodewords().len() {
+            write!(f, "CW {codewordsRow:3}:")?;
+            for column in &self.detectionRXingResultColumns {
+                match column
                     .as_ref()
-                    .unwrap()
-                    .getCodewords()[codewordsRow];
-                if codeword.is_none() {
-                    write!(f, "    |   ")?;
-                    // formatter.format("    |   ");
-                    continue;
+                    .and_then(|column| column.getCodewords().get(codewordsRow))
+                {
+                    Some(Some(codeword)) => write!(
+                        f,
+                        " {:3}|{:3}",
+                        codeword.getRowNumber(),
+                        codeword.getValue()
+                    )?,
+                    _ => write!(f, "    |   ")?,
                 }
-                write!(
-                    f,
-                    " {}|{}",
-                    codeword.as_ref().unwrap().getRowNumber(),
-                    codeword.as_ref().unwrap().getValue()
-                )?;
-                //   formatter.format(" %3d|%3d", codeword.getRowNumber(), codeword.getValue());
             }
-            // formatter.format("%n");
             writeln!(f)?;
         }
-        //   return formatter.toString();
-        write!(f, "")
-        // }
+        Ok(())
     }
 }
 
@@ -0,0 +1,97 @@
+use std::rc::Rc;
+
+use crate::{common::BitMatrix, point};
+
+use super::{
+    BarcodeMetadata, BoundingBox, Codeword, DetectionRXingResult, DetectionRXingResultColumn,
+    DetectionRXingResultColumnTrait,
+};
+
+fn bounding_box() -> Rc<BoundingBox> {
+    let image = Rc::new(BitMatrix::with_single_dimension(10).unwrap());
+    Rc::new(
+        BoundingBox::new(
+            image,
+            Some(point(0.0, 0.0)),
+            Some(point(0.0, 2.0)),
+            Some(point(9.0, 0.0)),
+            Some(point(9.0, 2.0)),
+        )
+        .unwrap(),
+    )
+}
+
+fn codeword(rowNumber: i32, value: u32) -> Codeword {
+    let mut codeword = Codeword::new(0, 1, (rowNumber as u32 % 3) * 3, value);
+    codeword.setRowNumber(rowNumber);
+    codeword
+}
+
+#[test]
+fn test_display() {
+    let boundingBox = bounding_box();
+    let mut result =
+        DetectionRXingResult::new(BarcodeMetadata::new(1, 2, 1, 0), boundingBox.clone());
+    assert_eq!("", result.to_string());
+
+    let mut rowIndicator = DetectionRXingResultColumn::new_with_is_left(boundingBox.clone(), true);
+    rowIndicator.setCodeword(0, codeword(0, 1));
+    rowIndicator.setCodeword(2, codeword(1, 31));
+    let mut data = DetectionRXingResultColumn::new_column(boundingBox);
+    data.setCodeword(0, codeword(0, 900));
+    result
+        .setDetectionRXingResultColumn(0, Some(rowIndicator))
+        .unwrap();
+    result.setDetectionRXingResultColumn(1, Some(data)).unwrap();
+
+    assert_eq!(
+        [
+            "CW   0:   0|  1   0|900    |   ",
+            "CW   1:    |       |       |   ",
+            "CW   2:   1| 31    |       |   ",
+            "",
+        ]
+        .join("\n"),
+        result.to_string()
+    );
+}
+
+#[test]
+fn test_set_detection_result_column() {
+    let boundingBox = bounding_box();
+    let mut result =
+        DetectionRXingResult::new(BarcodeMetadata::new(1, 4, 0, 0), boundingBox.clone());
+    assert!(result
+        .setDetectionRXingResultColumn(
+            3,
+            Some(DetectionRXingResultColumn::new_column(boundingBox.clone()))
+        )
+        .is_err());
+
+    // row 0 of the left row indicator: value 1 encodes the upper row count 4
+    let mut rowIndicator = DetectionRXingResultColumn::new_with_is_left(boundingBox.clone(), true);
+    rowIndicator.setCodeword(0, Codeword::new(0, 1, 0, 1));
+    // a data codeword whose row number is still unknown
+    let mut data = DetectionRXingResultColumn::new_column(boundingBox.clone());
+    data.setCodeword(0, Codeword::new(0, 1, 0, 900));
+    result
+        .setDetectionRXingResultColumn(0, Some(rowIndicator))
+        .unwrap();
+    result.setDetectionRXingResultColumn(1, Some(data)).unwrap();
+    result.setBoundingBox(boundingBox.clone());
+    assert!(Rc::ptr_eq(&boundingBox, &result.getBoundingBox()));
+
+    let dataCodeword = result
+        .getDetectionRXingResultColumn(1)
+        .as_ref()
+        .unwrap()
+        .getCodewords()[0];
+    assert_eq!(900, dataCodeword.unwrap().getValue());
+    assert!(!dataCodeword.unwrap().hasValidRowNumber());
+    assert!(result.getDetectionRXingResultColumn(2).is_none());
+
+    let columns = result.getDetectionRXingResultColumns();
+    let dataCodeword = columns[1].as_ref().unwrap().getCodewords()[0].unwrap();
+    assert_eq!(0, dataCodeword.getRowNumber());
+    assert!(columns[2].is_none());
+}
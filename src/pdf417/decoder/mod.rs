@@ -28,3 +28,5 @@ pub mod pdf_417_scanning_decoder;
 
 #[cfg(test)]
 mod pdf_417_decoder_test_case;
+#[cfg(test)]
+mod detection_result_test_case;
@@ -107,8 +107,9 @@ pub fn decode(
 
     detectionRXingResult.setBoundingBox(boundingBox.clone());
     let maxBarcodeColumn = detectionRXingResult.getBarcodeColumnCount() + 1;
-    detectionRXingResult.setDetectionRXingResultColumn(0, leftRowIndicatorColumn);
-    detectionRXingResult.setDetectionRXingResultColumn(maxBarcodeColumn, rightRowIndicatorColumn);
+    detectionRXingResult.setDetectionRXingResultColumn(0, leftRowIndicatorColumn)?;
+    detectionRXingResult
+        .setDetectionRXingResultColumn(maxBarcodeColumn, rightRowIndicatorColumn)?;
 
     // let leftToRight = leftRowIndicatorColumn.is_some();
     for barcodeColumnCount in 1..=maxBarcodeColumn {
@@ -133,7 +134,7 @@ pub fn decode(
         };
 
         detectionRXingResult
-            .setDetectionRXingResultColumn(barcodeColumn, Some(detectionRXingResultColumn));
+            .setDetectionRXingResultColumn(barcodeColumn, Some(detectionRXingResultColumn))?;
 
         let mut startColumn: i32 = -1;
         let mut previousStartColumn = startColumn;
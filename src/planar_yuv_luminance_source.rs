@@ -263,7 +263,8 @@ impl LuminanceSource for PlanarYUVLuminanceSource {
         // If the caller asks for the entire underlying image, save the copy and give them the
         // original data. The docs specifically warn that result.length must be ignored.
         if width == self.data_width && height == self.data_height {
-            let mut v = self.yuv_data.clone();
+            // only the Y plane is luminance, drop any chroma data which follows it
+            let mut v = self.yuv_data[..width * height].to_vec();
             if self.invert {
                 v = self.invert_block_of_bytes(v);
             }
@@ -276,7 +277,7 @@ impl LuminanceSource for PlanarYUVLuminanceSource {
 
         // If the width matches the full width of the underlying data, perform a single copy.
         if width == self.data_width {
-            matrix[0..area].clone_from_slice(&self.yuv_data[inputOffset..area]);
+            matrix[0..area].clone_from_slice(&self.yuv_data[inputOffset..inputOffset + area]);
             if self.invert {
                 matrix = self.invert_block_of_bytes(matrix);
             }
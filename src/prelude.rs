@@ -0,0 +1,36 @@
+//! Commonly used types, re-exported so that a single glob import is enough for most uses.
+//!
+//! ```
+//! use rxing::prelude::*;
+//!
+//! let matrix = MultiFormatWriter::default()
+//!     .encode("rxing prelude", &BarcodeFormat::QR_CODE, 100, 100)
+//!     .unwrap();
+//!
+//! let mut luma = Vec::with_capacity((matrix.getWidth() * matrix.getHeight()) as usize);
+//! for y in 0..matrix.getHeight() {
+//!     for x in 0..matrix.getWidth() {
+//!         luma.push(if matrix.get(x, y) { 0 } else { u8::MAX });
+//!     }
+//! }
+//!
+//! let mut image = BinaryBitmap::new(HybridBinarizer::new(Luma8LuminanceSource::new(
+//!     luma,
+//!     matrix.getWidth(),
+//!     matrix.getHeight(),
+//! )));
+//! let result = MultiFormatReader::default().decode(&mut image).unwrap();
+//!
+//! assert_eq!(&BarcodeFormat::QR_CODE, result.getBarcodeFormat());
+//! assert_eq!("rxing prelude", result.getText());
+//! ```
+
+pub use crate::common::{
+    BitArray, BitMatrix, BitSource, DecoderRXingResult, GlobalHistogramBinarizer, HybridBinarizer,
+    PerspectiveTransform,
+};
+pub use crate::{
+    BarcodeFormat, Binarizer, BinaryBitmap, DecodeHintType, DecodeHintValue, Exceptions,
+    Luma8LuminanceSource, LuminanceSource, MultiFormatReader, MultiFormatWriter, RXingResult,
+    Reader, Writer,
+};
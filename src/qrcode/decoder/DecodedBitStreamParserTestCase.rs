@@ -17,7 +17,7 @@
 use std::collections::HashMap;
 
 use crate::{
-    common::BitSourceBuilder,
+    common::{BitSource, BitSourceBuilder, ECIStringBuilder},
     qrcode::decoder::{decoded_bit_stream_parser, ErrorCorrectionLevel, Version},
 };
 
@@ -133,3 +133,53 @@ fn testHanziLevel1() {
 }
 
 // TODO definitely need more tests here
+
+#[test]
+fn testDecodeNumeric() {
+    let mut builder = BitSourceBuilder::new();
+    builder.write(123, 10);
+    builder.write(456, 10);
+    builder.write(78, 7);
+    builder.write(9, 4);
+    let mut bits = BitSource::new(builder.toByteArray().to_vec());
+
+    let mut result = ECIStringBuilder::default();
+    decoded_bit_stream_parser::decode_numeric(&mut bits, 6, &mut result).expect("unwrap");
+    assert_eq!("123456", result.to_string());
+
+    // two digits remain, then a single one
+    decoded_bit_stream_parser::decode_numeric(&mut bits, 2, &mut result).expect("unwrap");
+    decoded_bit_stream_parser::decode_numeric(&mut bits, 1, &mut result).expect("unwrap");
+    assert_eq!("123456789", result.to_string());
+
+    let mut builder = BitSourceBuilder::new();
+    builder.write(1000, 10);
+    let mut bits = BitSource::new(builder.toByteArray().to_vec());
+    assert!(decoded_bit_stream_parser::decode_numeric(
+        &mut bits,
+        3,
+        &mut ECIStringBuilder::default()
+    )
+    .is_err());
+}
+
+#[test]
+fn testDecodeAlphanumeric() {
+    let mut builder = BitSourceBuilder::new();
+    builder.write(10 * 45 + 11, 11); // "AB"
+    builder.write(36 * 45 + 44, 11); // " :"
+    builder.write(43, 6); // "/"
+    let mut bits = BitSource::new(builder.toByteArray().to_vec());
+
+    let mut result = ECIStringBuilder::default();
+    decoded_bit_stream_parser::decode_alphanumeric(&mut bits, 5, &mut result).expect("unwrap");
+    assert_eq!("AB :/", result.to_string());
+
+    let mut bits = BitSource::new(vec![0xFF]);
+    assert!(decoded_bit_stream_parser::decode_alphanumeric(
+        &mut bits,
+        2,
+        &mut ECIStringBuilder::default()
+    )
+    .is_err());
+}
@@ -108,3 +108,16 @@ fn testDecodeWithMisread() {
         .unwrap()
     );
 }
+
+#[test]
+fn testFormatInfoCoordinates() {
+    let (aroundTopLeft, split) = FormatInformation::format_info_coordinates(21);
+    assert_eq!((0, 8), aroundTopLeft[0]);
+    assert_eq!((7, 8), aroundTopLeft[6]);
+    assert_eq!((8, 7), aroundTopLeft[8]);
+    assert_eq!((8, 0), aroundTopLeft[14]);
+    assert_eq!((8, 20), split[0]);
+    assert_eq!((8, 14), split[6]);
+    assert_eq!((13, 8), split[7]);
+    assert_eq!((20, 8), split[14]);
+}
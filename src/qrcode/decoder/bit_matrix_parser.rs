@@ -64,29 +64,19 @@ impl BitMatrixParser {
             return self.parsedFormatInfo.as_ref().ok_or(Exceptions::PARSE);
         }
 
+        let (aroundTopLeft, split) =
+            FormatInformation::format_info_coordinates(self.bitMatrix.getHeight());
+
         // Read top-left format info bits
         let mut formatInfoBits1 = 0;
-        for i in 0..6 {
-            formatInfoBits1 = self.copyBit(i, 8, formatInfoBits1);
-        }
-        // .. and skip a bit in the timing pattern ...
-        formatInfoBits1 = self.copyBit(7, 8, formatInfoBits1);
-        formatInfoBits1 = self.copyBit(8, 8, formatInfoBits1);
-        formatInfoBits1 = self.copyBit(8, 7, formatInfoBits1);
-        // .. and skip a bit in the timing pattern ...
-        for j in (0..=5).rev() {
-            formatInfoBits1 = self.copyBit(8, j, formatInfoBits1);
+        for (i, j) in aroundTopLeft {
+            formatInfoBits1 = self.copyBit(i, j, formatInfoBits1);
         }
 
         // Read the top-right/bottom-left pattern too
-        let dimension = self.bitMatrix.getHeight();
         let mut formatInfoBits2 = 0;
-        let jMin = dimension - 7;
-        for j in (jMin..=dimension - 1).rev() {
-            formatInfoBits2 = self.copyBit(8, j, formatInfoBits2);
-        }
-        for i in (dimension - 8)..dimension {
-            formatInfoBits2 = self.copyBit(i, 8, formatInfoBits2);
+        for (i, j) in split {
+            formatInfoBits2 = self.copyBit(i, j, formatInfoBits2);
         }
 
         self.parsedFormatInfo =
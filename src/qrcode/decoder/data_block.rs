@@ -17,7 +17,7 @@
 use crate::common::Result;
 use crate::Exceptions;
 
-use super::{ErrorCorrectionLevel, VersionRef};
+use super::{ECBlocks, ErrorCorrectionLevel, VersionRef};
 
 /**
  * <p>Encapsulates a block of data within a QR Code. QR Codes may split their data into
@@ -62,80 +62,122 @@ impl DataBlock {
         // Figure out the number and size of data blocks used by this version and
         // error correction level
         let ecBlocks = version.getECBlocksForLevel(ecLevel);
+        let blocks = deinterleave_blocks(rawCodewords, ecBlocks)?;
 
-        // First count the total number of data blocks
-        let mut _totalBlocks = 0;
-        let ecBlockArray = ecBlocks.getECBlocks();
-        for ecBlock in ecBlockArray {
-            _totalBlocks += ecBlock.getCount();
-        }
+        Ok(blocks
+            .into_iter()
+            .zip(block_data_codewords(ecBlocks))
+            .map(|(codewords, numDataCodewords)| DataBlock::new(numDataCodewords, codewords))
+            .collect())
+    }
 
-        // Now establish DataBlocks of the appropriate size and number of data codewords
-        let mut result = Vec::new();
-        let mut numRXingResultBlocks = 0;
-        for ecBlock in ecBlockArray {
-            for _i in 0..ecBlock.getCount() {
-                let numDataCodewords = ecBlock.getDataCodewords();
-                let numBlockCodewords = ecBlocks.getECCodewordsPerBlock() + numDataCodewords;
-                result.push(DataBlock::new(
-                    numDataCodewords,
-                    vec![0u8; numBlockCodewords as usize],
-                ));
-                numRXingResultBlocks += 1;
-            }
-        }
+    pub fn getNumDataCodewords(&self) -> u32 {
+        self.numDataCodewords
+    }
 
-        // All blocks have the same amount of data, except that the last n
-        // (where n may be 0) have 1 more byte. Figure out where these start.
-        let shorterBlocksTotalCodewords = result[0].codewords.len();
-        let mut longerBlocksStartAt = result.len() - 1;
-        loop {
-            let numCodewords = result[longerBlocksStartAt].codewords.len();
+    pub fn getCodewords(&self) -> &[u8] {
+        &self.codewords
+    }
+}
 
-            if numCodewords == shorterBlocksTotalCodewords {
-                break;
-            }
-            longerBlocksStartAt -= 1;
-        }
-        longerBlocksStartAt += 1;
+/// The number of data codewords of each block, in block order.
+fn block_data_codewords(ecBlocks: &ECBlocks) -> impl Iterator<Item = u32> + '_ {
+    ecBlocks.getECBlocks().iter().flat_map(|ecBlock| {
+        std::iter::repeat_n(ecBlock.getDataCodewords(), ecBlock.getCount() as usize)
+    })
+}
 
-        let shorterBlocksNumDataCodewords =
-            shorterBlocksTotalCodewords - ecBlocks.getECCodewordsPerBlock() as usize;
-        // The last elements of result may be 1 element longer;
-        // first fill out as many elements as all of them have
-        let mut rawCodewordsOffset = 0;
-        for i in 0..shorterBlocksNumDataCodewords {
-            for result_j in result.iter_mut().take(numRXingResultBlocks) {
-                result_j.codewords[i] = rawCodewords[rawCodewordsOffset];
-                rawCodewordsOffset += 1;
+/**
+ * Separates codewords as laid out in a QR Code into their blocks, the inverse of
+ * {@link #interleave_blocks}.
+ *
+ * The symbol holds the first data codeword of every block, then the second ones and so on,
+ * skipping blocks with fewer data codewords, followed by the error correction codewords in the
+ * same way.
+ *
+ * @param codewords interleaved data and error correction codewords of all blocks
+ * @param ecBlocks block structure of the symbol's version and error correction level
+ * @return the data followed by the error correction codewords of each block
+ * @throws IllegalArgumentException if the number of codewords doesn't match ecBlocks
+ */
+pub fn deinterleave_blocks(codewords: &[u8], ecBlocks: &ECBlocks) -> Result<Vec<Vec<u8>>> {
+    let numEcCodewords = ecBlocks.getECCodewordsPerBlock() as usize;
+    let dataCodewords: Vec<usize> = block_data_codewords(ecBlocks)
+        .map(|numDataCodewords| numDataCodewords as usize)
+        .collect();
+    let total = dataCodewords.iter().sum::<usize>() + numEcCodewords * dataCodewords.len();
+    if codewords.len() != total {
+        return Err(Exceptions::illegal_argument_with(format!(
+            "expected {total} codewords, got {}",
+            codewords.len()
+        )));
+    }
+
+    let mut blocks: Vec<Vec<u8>> = dataCodewords
+        .iter()
+        .map(|numDataCodewords| Vec::with_capacity(numDataCodewords + numEcCodewords))
+        .collect();
+    let mut codewords = codewords.iter().copied();
+    let maxDataCodewords = dataCodewords.iter().copied().max().unwrap_or(0);
+    for i in 0..maxDataCodewords {
+        for (block, numDataCodewords) in blocks.iter_mut().zip(&dataCodewords) {
+            if i < *numDataCodewords {
+                block.extend(codewords.next());
             }
         }
-        // Fill out the last data block in the longer ones
-        for res in result
-            .iter_mut()
-            .take(numRXingResultBlocks)
-            .skip(longerBlocksStartAt)
-        {
-            res.codewords[shorterBlocksNumDataCodewords] = rawCodewords[rawCodewordsOffset];
-            rawCodewordsOffset += 1;
-        }
-        // Now add in error correction blocks
-        let max = result[0].codewords.len();
-        for i in shorterBlocksNumDataCodewords..max {
-            for (j, res) in result.iter_mut().enumerate().take(numRXingResultBlocks) {
-                let iOffset = if j < longerBlocksStartAt { i } else { i + 1 };
-                res.codewords[iOffset] = rawCodewords[rawCodewordsOffset];
-                rawCodewordsOffset += 1;
-            }
+    }
+    for _i in 0..numEcCodewords {
+        for block in blocks.iter_mut() {
+            block.extend(codewords.next());
         }
-        Ok(result)
     }
+    Ok(blocks)
+}
 
-    pub fn getNumDataCodewords(&self) -> u32 {
-        self.numDataCodewords
+/**
+ * Interleaves the codewords of each block in the order they are laid out in a QR Code, the
+ * inverse of {@link #deinterleave_blocks}.
+ *
+ * @param blocks the data followed by the error correction codewords of each block
+ * @param ecBlocks block structure of the symbol's version and error correction level
+ * @return interleaved codewords of all blocks
+ * @throws IllegalArgumentException if the blocks don't match ecBlocks
+ */
+pub fn interleave_blocks(blocks: &[Vec<u8>], ecBlocks: &ECBlocks) -> Result<Vec<u8>> {
+    let numEcCodewords = ecBlocks.getECCodewordsPerBlock() as usize;
+    let dataCodewords: Vec<usize> = block_data_codewords(ecBlocks)
+        .map(|numDataCodewords| numDataCodewords as usize)
+        .collect();
+    if blocks.len() != dataCodewords.len() {
+        return Err(Exceptions::illegal_argument_with(format!(
+            "expected {} blocks, got {}",
+            dataCodewords.len(),
+            blocks.len()
+        )));
+    }
+    for (i, (block, numDataCodewords)) in blocks.iter().zip(&dataCodewords).enumerate() {
+        if block.len() != numDataCodewords + numEcCodewords {
+            return Err(Exceptions::illegal_argument_with(format!(
+                "block {i} has {} codewords, expected {}",
+                block.len(),
+                numDataCodewords + numEcCodewords
+            )));
+        }
     }
 
-    pub fn getCodewords(&self) -> &[u8] {
-        &self.codewords
+    let mut result = Vec::with_capacity(blocks.iter().map(Vec::len).sum());
+    let maxDataCodewords = dataCodewords.iter().copied().max().unwrap_or(0);
+    for i in 0..maxDataCodewords {
+        for (block, numDataCodewords) in blocks.iter().zip(&dataCodewords) {
+            if i < *numDataCodewords {
+                result.push(block[i]);
+            }
+        }
+    }
+    for i in 0..numEcCodewords {
+        for (block, numDataCodewords) in blocks.iter().zip(&dataCodewords) {
+            result.push(block[numDataCodewords + i]);
+        }
     }
+    Ok(result)
 }
@@ -0,0 +1,58 @@
+use crate::qrcode::decoder::{
+    deinterleave_blocks, interleave_blocks, DataBlock, ErrorCorrectionLevel, Version,
+};
+
+#[test]
+fn test_single_block() {
+    // 1-L: one block of 19 data and 7 error correction codewords
+    let ecBlocks = Version::getVersionForNumber(1)
+        .unwrap()
+        .getECBlocksForLevel(ErrorCorrectionLevel::L);
+    let codewords: Vec<u8> = (0..26).collect();
+
+    let blocks = deinterleave_blocks(&codewords, ecBlocks).unwrap();
+    assert_eq!(vec![codewords.clone()], blocks);
+    assert_eq!(codewords, interleave_blocks(&blocks, ecBlocks).unwrap());
+}
+
+#[test]
+fn test_mixed_blocks() {
+    // 5-Q: two blocks of 15 and two of 16 data codewords, 18 error correction codewords each
+    let version = Version::getVersionForNumber(5).unwrap();
+    let ecBlocks = version.getECBlocksForLevel(ErrorCorrectionLevel::Q);
+    let blocks: Vec<Vec<u8>> = [15_u8, 15, 16, 16]
+        .iter()
+        .enumerate()
+        .map(|(i, numDataCodewords)| {
+            (0..numDataCodewords + 18)
+                .map(|j| (i as u8) << 6 | j)
+                .collect()
+        })
+        .collect();
+
+    let codewords = interleave_blocks(&blocks, ecBlocks).unwrap();
+    assert_eq!(134, codewords.len());
+    assert_eq!(&[0x00, 0x40, 0x80, 0xC0, 0x01], &codewords[..5]);
+    // only the longer blocks have a 16th data codeword
+    assert_eq!(
+        &[0x0E, 0x4E, 0x8E, 0xCE, 0x8F, 0xCF, 0x0F],
+        &codewords[56..63]
+    );
+
+    assert_eq!(blocks, deinterleave_blocks(&codewords, ecBlocks).unwrap());
+    let dataBlocks =
+        DataBlock::getDataBlocks(&codewords, version, ErrorCorrectionLevel::Q).unwrap();
+    for (dataBlock, block) in dataBlocks.iter().zip(&blocks) {
+        assert_eq!(block.as_slice(), dataBlock.getCodewords());
+    }
+    assert_eq!(
+        vec![15, 15, 16, 16],
+        dataBlocks
+            .iter()
+            .map(DataBlock::getNumDataCodewords)
+            .collect::<Vec<_>>()
+    );
+
+    assert!(deinterleave_blocks(&codewords[1..], ecBlocks).is_err());
+    assert!(interleave_blocks(&blocks[1..], ecBlocks).is_err());
+}
@@ -152,7 +152,7 @@ pub fn decode(
 
     Ok(DecoderRXingResult::with_all(
         bytes.to_owned(),
-        result.build_result().to_string(),
+        result.build_result()?.to_string(),
         byteSegments.to_vec(),
         format!("{}", u8::from(ecLevel)),
         symbolSequence,
@@ -337,6 +337,26 @@ fn decodeByteSegment(
     Ok(())
 }
 
+/// Decodes `count` digits of a numeric mode segment from `src` into `out`.
+///
+/// Digits are read in groups of three from 10 bits each; a remainder of two digits takes
+/// 7 bits and a single remaining digit 4 bits.
+pub fn decode_numeric(src: &mut BitSource, count: usize, out: &mut ECIStringBuilder) -> Result<()> {
+    decodeNumericSegment(src, out, count)
+}
+
+/// Decodes `count` characters of an alphanumeric mode segment from `src` into `out`.
+///
+/// Characters are read in pairs from 11 bits each; an odd final character takes 6 bits.
+/// No FNC1 processing is applied.
+pub fn decode_alphanumeric(
+    src: &mut BitSource,
+    count: usize,
+    out: &mut ECIStringBuilder,
+) -> Result<()> {
+    decodeAlphanumericSegment(src, out, count, false)
+}
+
 fn toAlphaNumericChar(value: u32) -> Result<char> {
     if value as usize >= ALPHANUMERIC_CHARS.len() {
         return Err(Exceptions::FORMAT);
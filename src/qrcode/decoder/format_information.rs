@@ -58,6 +58,9 @@ const FORMAT_INFO_DECODE_LOOKUP: [[u32; 2]; 32] = [
     [0x2BED, 0x1F],
 ];
 
+/// The `(x, y)` module coordinates of one copy of the format information bits.
+pub type FormatInfoCoordinates = [(u32, u32); 15];
+
 /**
  * <p>Encapsulates a QR Code's format information, including the data mask used and
  * error correction level.</p>
@@ -84,6 +87,37 @@ impl FormatInformation {
         })
     }
 
+    /// Module coordinates, as `(x, y)`, of the two copies of the 15 format information bits in
+    /// a symbol `dimension` modules wide, most significant bit first.
+    ///
+    /// The first copy surrounds the top-left finder pattern. The second copy is split between
+    /// the bottom-left finder pattern (7 bits) and the top-right one (8 bits).
+    pub fn format_info_coordinates(
+        dimension: u32,
+    ) -> (FormatInfoCoordinates, FormatInfoCoordinates) {
+        let around_top_left = std::array::from_fn(|i| {
+            let i = i as u32;
+            match i {
+                0..=5 => (i, 8),
+                // .. skipping the timing pattern ...
+                6 => (7, 8),
+                7 => (8, 8),
+                8 => (8, 7),
+                _ => (8, 14 - i),
+            }
+        });
+        let split = std::array::from_fn(|i| {
+            let i = i as u32;
+            if i < 7 {
+                (8, dimension - 1 - i)
+            } else {
+                (dimension - 15 + i, 8)
+            }
+        });
+
+        (around_top_left, split)
+    }
+
     pub fn numBitsDiffering(a: u32, b: u32) -> u32 {
         (a ^ b).count_ones()
         // return Integer.bitCount(a ^ b);
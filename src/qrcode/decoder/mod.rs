@@ -20,6 +20,8 @@ mod ModeTestCase;
 #[cfg(test)]
 mod VersionTestCase;
 #[cfg(test)]
+mod data_block_test_case;
+#[cfg(test)]
 mod data_mask_testcase;
 
 pub use bit_matrix_parser::*;
@@ -18,8 +18,8 @@ use std::ops::Div;
 
 use crate::{
     common::{BitMatrix, Result},
-    result_point_utils, DecodeHintType, DecodeHintValue, DecodingHintDictionary, Exceptions, Point,
-    PointCallback,
+    result_point_utils, DecodeHintType, DecodeHintValue, DecodingHintDictionary, Exceptions,
+    NotFoundReason, Point, PointCallback,
 };
 
 use super::{FinderPattern, FinderPatternInfo};
@@ -695,7 +695,10 @@ impl<'a> FinderPatternFinder<'_> {
         let startSize = self.possibleCenters.len();
         if startSize < 3 {
             // Couldn't find enough finder patterns
-            return Err(Exceptions::NOT_FOUND);
+            return Err(Exceptions::not_found_because(
+                NotFoundReason::NoFinderPattern,
+                "Couldn't find enough finder patterns",
+            ));
         }
 
         self.possibleCenters
@@ -775,12 +778,11 @@ impl<'a> FinderPatternFinder<'_> {
             }
         }
 
-        if distortion == f64::MAX {
-            return Err(Exceptions::NOT_FOUND);
-        }
-
-        if bestPatterns[0].is_none() {
-            return Err(Exceptions::NOT_FOUND);
+        if distortion == f64::MAX || bestPatterns[0].is_none() {
+            return Err(Exceptions::not_found_because(
+                NotFoundReason::NoFinderPattern,
+                "no finder pattern triple fits",
+            ));
         }
 
         let p1 = bestPatterns[0].ok_or(Exceptions::NOT_FOUND)?;
@@ -18,12 +18,13 @@ use std::collections::HashMap;
 
 use crate::{
     common::{
-        BitMatrix, DefaultGridSampler, GridSampler, PerspectiveTransform, Quadrilateral, Result,
-        SamplerControl,
+        detector::black_white_black_run_centered, BitMatrix, GridSamplerProvider,
+        PerspectiveTransform, Quadrilateral, Result, SamplerControl,
     },
     point,
     qrcode::decoder::Version,
-    DecodeHintType, DecodeHintValue, DecodingHintDictionary, Exceptions, Point, PointCallback,
+    DecodeHintType, DecodeHintValue, DecodingHintDictionary, Exceptions, NotFoundReason, Point,
+    PointCallback,
 };
 
 use super::{
@@ -217,7 +218,7 @@ impl<'a> Detector<'_> {
         transform: PerspectiveTransform,
         dimension: u32,
     ) -> Result<BitMatrix> {
-        let sampler = DefaultGridSampler::default();
+        let sampler = GridSamplerProvider::get_grid_sampler();
         sampler.sample_grid(
             image,
             dimension,
@@ -244,7 +245,12 @@ impl<'a> Detector<'_> {
         match dimension & 0x03 {
             0 => dimension += 1,
             2 => dimension -= 1,
-            3 => return Err(Exceptions::NOT_FOUND),
+            3 => {
+                return Err(Exceptions::not_found_because(
+                    NotFoundReason::DimensionMismatch,
+                    format!("estimated dimension {dimension} is not a valid QR code size"),
+                ))
+            }
             _ => {}
         }
         Ok(dimension as u32)
@@ -350,70 +356,11 @@ impl<'a> Detector<'_> {
      * may be skewed or rotated.</p>
      */
     fn sizeOfBlackWhiteBlackRun(&self, fromX: u32, fromY: u32, toX: u32, toY: u32) -> f32 {
-        let mut fromX = fromX;
-        let mut fromY = fromY;
-        let mut toX = toX;
-        let mut toY = toY;
-        // Mild variant of Bresenham's algorithm;
-        // see http://en.wikipedia.org/wiki/Bresenham's_line_algorithm
-        let steep = (toY as i64 - fromY as i64).abs() > (toX as i64 - fromX as i64).abs();
-        if steep {
-            std::mem::swap(&mut fromX, &mut fromY);
-            std::mem::swap(&mut toX, &mut toY);
-        }
-
-        let dx: i32 = (toX as i64 - fromX as i64).abs() as i32;
-        let dy: i32 = (toY as i64 - fromY as i64).abs() as i32;
-        let mut error = -dx / 2;
-        let xstep: i32 = if fromX < toX { 1 } else { -1 };
-        let ystep: i32 = if fromY < toY { 1 } else { -1 };
-
-        // In black pixels, looking for white, first or second time.
-        let mut state = 0;
-        // Loop up until x == toX, but not beyond
-        let xLimit = toX as i32 + xstep;
-
-        let mut x: i32 = fromX as i32;
-        let mut y: i32 = fromY as i32;
-        while x != xLimit {
-            let realX = if steep { y } else { x };
-            let realY = if steep { x } else { y };
-
-            // Does current pixel mean we have moved white to black or vice versa?
-            // Scanning black in state 0,2 and white in state 1, so if we find the wrong
-            // color, advance to next state or end if we are in state 2 already
-            if (state == 1) == self.image.get(realX as u32, realY as u32) {
-                if state == 2 {
-                    return Point::distance(
-                        point(x as f32, y as f32),
-                        point(fromX as f32, fromY as f32),
-                    );
-                }
-                state += 1;
-            }
-
-            error += dy;
-            if error > 0 {
-                if y == toY as i32 {
-                    break;
-                }
-                y += ystep;
-                error -= dx;
-            }
-
-            x += xstep;
-        }
-        // Found black-white-black; give the benefit of the doubt that the next pixel outside the image
-        // is "white" so this last point at (toX+xStep,toY) is the right ending. This is really a
-        // small approximation; (toX+xStep,toY+yStep) might be really correct. Ignore this.
-        if state == 2 {
-            return Point::distance(
-                point((toX as i32 + xstep) as f32, toY as f32),
-                point(fromX as f32, fromY as f32),
-            );
-        }
-        // else we didn't find even black-white-black; no estimate is really possible
-        f32::NAN
+        black_white_black_run_centered(
+            self.image,
+            (fromX as i32, fromY as i32),
+            (toX as i32, toY as i32),
+        )
     }
 
     /**
@@ -20,7 +20,7 @@ use crate::{
     common::{BitArray, CharacterSet},
     qrcode::{
         decoder::{ErrorCorrectionLevel, Mode, Version},
-        encoder::{qrcode_encoder, MinimalEncoder},
+        encoder::{estimate_qr_data_bits, qrcode_encoder, MinimalEncoder},
     },
     EncodeHintType, EncodeHintValue,
 };
@@ -618,7 +618,8 @@ fn testInterleaveWithECBytes() {
     ];
     assert_eq!(expected.len(), out.getSizeInBytes());
     let mut outArray = vec![0u8; expected.len()];
-    out.toBytes(0, &mut outArray, 0, expected.len());
+    out.toBytes(0, &mut outArray, 0, expected.len())
+        .expect("to bytes");
     // Can't use Arrays.equals(), because outArray may be longer than out.sizeInBytes()
     for x in 0..expected.len() {
         // for (int x = 0; x < expected.length; x++) {
@@ -651,7 +652,8 @@ fn testInterleaveWithECBytes() {
     ];
     assert_eq!(expected.len(), out.getSizeInBytes());
     outArray = vec![0u8; expected.len()];
-    out.toBytes(0, &mut outArray, 0, expected.len());
+    out.toBytes(0, &mut outArray, 0, expected.len())
+        .expect("to bytes");
     for x in 0..expected.len() {
         // for (int x = 0; x < expected.length; x++) {
         assert_eq!(expected[x], outArray[x]);
@@ -1198,3 +1200,28 @@ fn shiftJISString(bytes: &[u8]) -> String {
         .expect("decode should be ok")
     // return new String(bytes, StringUtils.SHIFT_JIS_CHARSET);
 }
+
+#[test]
+fn test_estimate_qr_data_bits() {
+    // "ABCDEFGHIJ" in ALPHANUMERIC: 4 mode bits, 10 chars as 5 pairs of 11 bits = 55 bits
+    // "0123456789012345" in NUMERIC: 4 mode bits, 5 triples of 10 bits + 1 digit of 4 = 54 bits
+    // "Grüße Ő" in BYTE: ECI 4 + 8 bits for ISO-8859-2, 4 mode bits, 7 bytes = 56 bits
+    let text = "ABCDEFGHIJ0123456789012345Grüße Ő";
+    // plus the character count indicators of each segment, which depend on the size class
+    for (versionNumber, expected) in [
+        // 9, 10 and 8 bits
+        (1, 68 + 68 + 80),
+        (9, 68 + 68 + 80),
+        // 11, 12 and 16 bits
+        (10, 70 + 70 + 88),
+        // 13, 14 and 16 bits
+        (27, 72 + 72 + 88),
+    ] {
+        let version = Version::getVersionForNumber(versionNumber).unwrap();
+        assert_eq!(
+            expected,
+            estimate_qr_data_bits(text, None, false, version).expect("estimate"),
+            "version {versionNumber}"
+        );
+    }
+}
@@ -611,6 +611,29 @@ impl MinimalEncoder {
     }
 }
 
+/**
+ * Computes how many bits the minimal encoding of a string takes in a symbol of the given version:
+ * the mode headers, character count indicators, data and any ECI switches, before termination
+ * and padding. Compare the result against the capacity of the version to see whether a string
+ * fits without encoding it.
+ *
+ * @param text The string to encode
+ * @param priorityCharset The preferred {@link Charset}, as for {@link MinimalEncoder#new}
+ * @param isGS1 {@code true} if a FNC1 is to be prepended; {@code false} otherwise
+ * @param version The version the string would be encoded in. Only its size class (versions 1-9,
+ *   10-26 or 27-40) affects the result.
+ * @return the number of bits of the optimal segmentation
+ */
+pub fn estimate_qr_data_bits(
+    text: &str,
+    priorityCharset: Option<CharacterSet>,
+    isGS1: bool,
+    version: VersionRef,
+) -> Result<usize> {
+    let encoder = MinimalEncoder::new(text, priorityCharset, isGS1, ErrorCorrectionLevel::L);
+    Ok(encoder.encodeSpecificVersion(version)?.getSize() as usize)
+}
+
 pub struct Edge {
     pub mode: Mode,
     fromPosition: usize,
@@ -532,7 +532,7 @@ pub fn interleaveWithECBytes(
 
         let size = numDataBytesInBlock;
         let mut dataBytes = vec![0u8; size as usize];
-        bits.toBytes(8 * data_bytes_offset, &mut dataBytes, 0, size as usize);
+        bits.toBytes(8 * data_bytes_offset, &mut dataBytes, 0, size as usize)?;
         let ec_bytes = generateECBytes(&dataBytes, numEcBytesInBlock as usize)?;
         blocks.push(BlockPair::new(dataBytes, ec_bytes.clone()));
 
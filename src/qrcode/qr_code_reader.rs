@@ -147,21 +147,15 @@ impl QRCodeReader {
      * case.
      */
     fn extractPureBits(image: &BitMatrix) -> Result<BitMatrix> {
-        let leftTopBlack = image.getTopLeftOnBit();
-        let rightBottomBlack = image.getBottomRightOnBit();
-        if leftTopBlack.is_none() || rightBottomBlack.is_none() {
-            return Err(Exceptions::NOT_FOUND);
-        }
-
-        let leftTopBlack = leftTopBlack.ok_or(Exceptions::INDEX_OUT_OF_BOUNDS)?;
-        let rightBottomBlack = rightBottomBlack.ok_or(Exceptions::INDEX_OUT_OF_BOUNDS)?;
+        let leftTopBlack = image.top_left_on_bit().ok_or(Exceptions::NOT_FOUND)?;
+        let rightBottomBlack = image.bottom_right_on_bit().ok_or(Exceptions::NOT_FOUND)?;
 
-        let moduleSize = Self::moduleSize(&leftTopBlack, image)?;
+        let moduleSize = Self::moduleSize(leftTopBlack, image)?;
 
-        let mut top = leftTopBlack[1] as i32;
-        let bottom = rightBottomBlack[1] as i32;
-        let mut left = leftTopBlack[0] as i32;
-        let mut right = rightBottomBlack[0] as i32;
+        let mut top = leftTopBlack.1 as i32;
+        let bottom = rightBottomBlack.1 as i32;
+        let mut left = leftTopBlack.0 as i32;
+        let mut right = rightBottomBlack.0 as i32;
 
         // Sanity check!
         if left >= right || top >= bottom {
@@ -229,11 +223,10 @@ impl QRCodeReader {
         Ok(bits)
     }
 
-    fn moduleSize(leftTopBlack: &[u32], image: &BitMatrix) -> Result<f32> {
+    fn moduleSize(leftTopBlack: (u32, u32), image: &BitMatrix) -> Result<f32> {
         let height = image.getHeight();
         let width = image.getWidth();
-        let mut x = leftTopBlack[0];
-        let mut y = leftTopBlack[1];
+        let (mut x, mut y) = leftTopBlack;
         let mut inBlack = true;
         let mut transitions = 0;
         while x < width && y < height {
@@ -250,6 +243,6 @@ impl QRCodeReader {
         if x == width || y == height {
             return Err(Exceptions::NOT_FOUND);
         }
-        Ok((x - leftTopBlack[0]) as f32 / 7.0)
+        Ok((x - leftTopBlack.0) as f32 / 7.0)
     }
 }
@@ -41,3 +41,26 @@ pub fn orderBestPatterns<T: Into<Point> + Copy>(patterns: &mut [T; 3]) {
 fn crossProductZ(a: Point, b: Point, c: Point) -> f32 {
     ((c.x - b.x) * (a.y - b.y)) - ((c.y - b.y) * (a.x - b.x))
 }
+
+/// The angle at vertex `b` between the rays towards `a` and `c`, in radians within `[0, PI]`.
+///
+/// Returns 0 if `b` coincides with `a` or `c`.
+pub fn angle_between(a: Point, b: Point, c: Point) -> f32 {
+    let ba = a - b;
+    let bc = c - b;
+    if ba.length() == 0.0 || bc.length() == 0.0 {
+        return 0.0;
+    }
+    ba.cross(bc).abs().atan2(ba.dot(bc))
+}
+
+/// Whether the three points lie on a common line, allowing one of them to be up to `tolerance`
+/// away from the line through the other two.
+pub fn are_collinear(a: Point, b: Point, c: Point, tolerance: f32) -> bool {
+    let longest = a.distance(b).max(b.distance(c)).max(a.distance(c));
+    if longest == 0.0 {
+        return true;
+    }
+    // twice the triangle's area over its longest side is its smallest height
+    crossProductZ(a, b, c).abs() / longest <= tolerance
+}
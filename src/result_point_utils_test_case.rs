@@ -0,0 +1,33 @@
+use std::f32::consts::{FRAC_PI_2, PI};
+
+use crate::{
+    point,
+    result_point_utils::{angle_between, are_collinear},
+};
+
+#[test]
+fn test_angle_between() {
+    let right_angle = angle_between(point(10.0, 0.0), point(0.0, 0.0), point(0.0, 10.0));
+    assert!((right_angle - FRAC_PI_2).abs() < 1e-5, "{right_angle}");
+
+    // the order of the outer points does not matter
+    let flipped = angle_between(point(0.0, 10.0), point(0.0, 0.0), point(10.0, 0.0));
+    assert!((flipped - FRAC_PI_2).abs() < 1e-5, "{flipped}");
+
+    let straight = angle_between(point(-3.0, 1.0), point(0.0, 1.0), point(5.0, 1.0));
+    assert!((straight - PI).abs() < 1e-5, "{straight}");
+
+    assert_eq!(
+        0.0,
+        angle_between(point(1.0, 1.0), point(1.0, 1.0), point(4.0, 5.0))
+    );
+}
+
+#[test]
+fn test_are_collinear() {
+    let (a, c) = (point(0.0, 0.0), point(100.0, 0.0));
+    assert!(are_collinear(a, point(50.0, 0.5), c, 1.0));
+    assert!(!are_collinear(a, point(50.0, 0.5), c, 0.1));
+    assert!(!are_collinear(a, point(50.0, 20.0), c, 1.0));
+    assert!(are_collinear(a, a, a, 0.0));
+}
@@ -131,31 +131,36 @@ impl LuminanceSource for RGBLuminanceSource {
 
 impl RGBLuminanceSource {
     pub fn new_with_width_height_pixels(width: usize, height: usize, pixels: &[u32]) -> Self {
-        let dataWidth = width;
-        let dataHeight = height;
-        let left = 0;
-        let top = 0;
-
-        // In order to measure pure decoding speed, we convert the entire image to a greyscale array
-        // up front, which is the same as the Y channel of the YUVLuminanceSource in the real app.
-        //
-        // Total number of pixels suffices, can ignore shape
-        let size = width * height;
-        let mut luminances: Vec<u8> = vec![0; size];
-        for offset in 0..size {
-            let pixel = pixels[offset];
+        Self::new_with_luminance_fn(width, height, pixels, |pixel| {
             let r = (pixel >> 16) & 0xff; // red
             let g2 = (pixel >> 7) & 0x1fe; // 2 * green
             let b = pixel & 0xff; // blue
-                                  // Calculate green-favouring average cheaply
-            luminances[offset] = ((r + g2 + b) / 4) as u8;
-        }
+            ((r + g2 + b) / 4) as u8
+        })
+    }
+
+    /// Builds a source from packed pixels using `luminance` to convert each of them, for pixel
+    /// layouts other than the 0xRRGGBB expected by [`RGBLuminanceSource::new_with_width_height_pixels`].
+    ///
+    /// The conversion runs exactly once per pixel, here; rows and matrices are later copied from
+    /// the stored luminance values.
+    pub fn new_with_luminance_fn<F: FnMut(u32) -> u8>(
+        width: usize,
+        height: usize,
+        pixels: &[u32],
+        luminance: F,
+    ) -> Self {
+        let luminances = pixels[..width * height]
+            .iter()
+            .copied()
+            .map(luminance)
+            .collect();
         Self {
             luminances,
-            dataWidth,
-            dataHeight,
-            left,
-            top,
+            dataWidth: width,
+            dataHeight: height,
+            left: 0,
+            top: 0,
             width,
             height,
             invert: false,
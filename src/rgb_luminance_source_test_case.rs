@@ -72,4 +72,25 @@ fn testGetRow() {
 //   assert_eq!("#+ \n#+#\n#+#\n", SOURCE.toString());
 // }
 
+#[test]
+fn test_luminance_computed_once() {
+    let mut conversions = 0;
+    let source = RGBLuminanceSource::new_with_luminance_fn(3, 3, SRC_DATA.as_ref(), |pixel| {
+        conversions += 1;
+        (pixel & 0xFF) as u8
+    });
+    assert_eq!(9, conversions);
+
+    for _i in 0..3 {
+        assert_eq!(
+            vec![0x00, 0x7F, 0xFF, 0x00, 0x00, 0xFF, 0xFF, 0x00, 0x00],
+            source.get_matrix()
+        );
+        assert_eq!(vec![0x00, 0x00, 0xFF], source.get_row(1));
+    }
+    let cropped = source.crop(1, 1, 2, 2).unwrap();
+    assert_eq!(vec![0x00, 0xFF, 0x00, 0x00], cropped.get_matrix());
+    assert_eq!(9, conversions);
+}
+
 // }
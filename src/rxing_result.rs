@@ -16,7 +16,10 @@
 
 use std::{collections::HashMap, fmt};
 
-use crate::{BarcodeFormat, Point, RXingResultMetadataType, RXingResultMetadataValue};
+use crate::{
+    common::DecoderRXingResult, BarcodeFormat, Point, RXingResultMetadataType,
+    RXingResultMetadataValue,
+};
 
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
@@ -83,6 +86,86 @@ impl RXingResult {
         }
     }
 
+    /**
+     * Wraps the output of a 2D decoder, carrying its byte segments, error correction level,
+     * structured append sequence and parity, content type, mirroring and, for formats which
+     * define one, symbology identifier over into result metadata.
+     */
+    pub fn from_decoder_result(
+        decoder: DecoderRXingResult,
+        format: BarcodeFormat,
+        points: Vec<Point>,
+    ) -> Self {
+        let mut result = Self::new(
+            decoder.getText(),
+            decoder.getRawBytes().clone(),
+            points,
+            format,
+        );
+
+        let byteSegments = decoder.getByteSegments();
+        if !byteSegments.is_empty() {
+            result.putMetadata(
+                RXingResultMetadataType::BYTE_SEGMENTS,
+                RXingResultMetadataValue::ByteSegments(byteSegments.clone()),
+            );
+        }
+        let ecLevel = decoder.getECLevel();
+        if !ecLevel.is_empty() {
+            result.putMetadata(
+                RXingResultMetadataType::ERROR_CORRECTION_LEVEL,
+                RXingResultMetadataValue::ErrorCorrectionLevel(ecLevel.to_owned()),
+            );
+        }
+        if decoder.hasStructuredAppend() {
+            result.putMetadata(
+                RXingResultMetadataType::STRUCTURED_APPEND_SEQUENCE,
+                RXingResultMetadataValue::StructuredAppendSequence(
+                    decoder.getStructuredAppendSequenceNumber(),
+                ),
+            );
+            result.putMetadata(
+                RXingResultMetadataType::STRUCTURED_APPEND_PARITY,
+                RXingResultMetadataValue::StructuredAppendParity(
+                    decoder.getStructuredAppendParity(),
+                ),
+            );
+        }
+        let contentType = decoder.getContentType();
+        if !contentType.is_empty() {
+            result.putMetadata(
+                RXingResultMetadataType::CONTENT_TYPE,
+                RXingResultMetadataValue::ContentType(contentType.to_owned()),
+            );
+        }
+        if decoder.getIsMirrored() {
+            result.putMetadata(
+                RXingResultMetadataType::IS_MIRRORED,
+                RXingResultMetadataValue::IsMirrored(true),
+            );
+        }
+
+        let symbology = match format {
+            BarcodeFormat::AZTEC => Some('z'),
+            BarcodeFormat::DATA_MATRIX => Some('d'),
+            BarcodeFormat::MAXICODE => Some('U'),
+            BarcodeFormat::PDF_417 => Some('L'),
+            BarcodeFormat::QR_CODE => Some('Q'),
+            _ => None,
+        };
+        if let Some(symbology) = symbology {
+            result.putMetadata(
+                RXingResultMetadataType::SYMBOLOGY_IDENTIFIER,
+                RXingResultMetadataValue::SymbologyIdentifier(format!(
+                    "]{symbology}{}",
+                    decoder.getSymbologyModifier()
+                )),
+            );
+        }
+
+        result
+    }
+
     pub fn new_from_existing_result(prev: Self, points: Vec<Point>) -> Self {
         Self {
             text: prev.text,